@@ -0,0 +1,272 @@
+//! In-process microphone capture for dictation. A GStreamer pipeline
+//! (`autoaudiosrc ! audioconvert ! audioresample ! capsfilter ! appsink`)
+//! resamples whatever the device natively provides down to the 16kHz mono
+//! S16LE Whisper expects, and [`Vad`] segments the resulting stream into
+//! speech chunks instead of requiring the caller to decide when to stop
+//! recording. Pipeline bus errors are appended to the same log file the
+//! Debug Logs page tails (see `log_view::parse_line`), so a device failure
+//! shows up there instead of only on stderr.
+
+use gstreamer as gst;
+use gstreamer::prelude::*;
+use gstreamer_app as gst_app;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Sample rate requested from the pipeline's capsfilter; matches what
+/// Whisper wants, so no resampling is needed downstream of this module.
+const SAMPLE_RATE: u32 = 16_000;
+
+/// How long the VAD spends sampling background noise before it starts
+/// flagging frames as speech (see [`Vad::new`]).
+const NOISE_FLOOR_WARMUP_MS: u64 = 300;
+
+/// Floor under `noise_floor` used when comparing against the speech
+/// threshold, so a near-silent room (noise floor close to 0) doesn't make
+/// every frame register as speech.
+const MIN_NOISE_FLOOR: f32 = 50.0;
+
+pub const DEFAULT_SILENCE_MS: u64 = 800;
+pub const DEFAULT_SPEECH_THRESHOLD: f32 = 2.5;
+
+pub struct CaptureConfig {
+    pub silence_ms: u64,
+    pub speech_threshold: f32,
+    pub log_file: PathBuf,
+}
+
+impl CaptureConfig {
+    pub fn new(log_file: PathBuf) -> Self {
+        CaptureConfig {
+            silence_ms: DEFAULT_SILENCE_MS,
+            speech_threshold: DEFAULT_SPEECH_THRESHOLD,
+            log_file,
+        }
+    }
+}
+
+/// A running capture pipeline. Dropping it (or calling [`stop`]) tears the
+/// pipeline down; `on_segment` stops being called once that happens.
+///
+/// [`stop`]: CapturePipeline::stop
+pub struct CapturePipeline {
+    pipeline: gst::Pipeline,
+    // Keeps the bus watch installed for as long as the pipeline lives —
+    // `BusWatchGuard::drop` removes the watch, so discarding this would
+    // silently stop EOS/Error messages from ever reaching `append_log_line`.
+    bus_watch: gst::bus::BusWatchGuard,
+}
+
+impl CapturePipeline {
+    /// Builds and starts the capture pipeline, invoking `on_segment` with
+    /// each closed speech segment's mono 16kHz S16LE samples, and `on_level`
+    /// with every buffer's normalized amplitude (0.0-1.0) as it arrives —
+    /// unlike `on_segment`, `on_level` fires continuously regardless of VAD
+    /// state, so callers wanting a live waveform don't have to wait for a
+    /// speech segment to close. Both callbacks run on whatever thread
+    /// GStreamer delivers the buffer on — not necessarily the glib main
+    /// thread — so neither must touch GTK widgets directly.
+    pub fn start(
+        config: CaptureConfig,
+        on_segment: impl Fn(Vec<i16>) + Send + 'static,
+        on_level: impl Fn(f32) + Send + 'static,
+    ) -> Result<Self, String> {
+        gst::init().map_err(|e| e.to_string())?;
+
+        let pipeline = gst::parse::launch(
+            "autoaudiosrc ! audioconvert ! audioresample ! \
+             capsfilter caps=audio/x-raw,rate=16000,channels=1,format=S16LE ! \
+             appsink name=sink sync=false",
+        )
+        .map_err(|e| e.to_string())?
+        .downcast::<gst::Pipeline>()
+        .map_err(|_| "capture pipeline description wasn't a bin".to_string())?;
+
+        let appsink = pipeline
+            .by_name("sink")
+            .ok_or("capture pipeline has no appsink named 'sink'")?
+            .downcast::<gst_app::AppSink>()
+            .map_err(|_| "'sink' element wasn't an appsink".to_string())?;
+
+        let vad = Arc::new(Mutex::new(Vad::new(config.silence_ms, config.speech_threshold)));
+
+        appsink.set_callbacks(
+            gst_app::AppSinkCallbacks::builder()
+                .new_sample(move |sink| {
+                    let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                    let Some(buffer) = sample.buffer() else {
+                        return Ok(gst::FlowSuccess::Ok);
+                    };
+                    let Ok(map) = buffer.map_readable() else {
+                        return Ok(gst::FlowSuccess::Ok);
+                    };
+
+                    let frame: Vec<i16> = map
+                        .as_slice()
+                        .chunks_exact(2)
+                        .map(|c| i16::from_le_bytes([c[0], c[1]]))
+                        .collect();
+
+                    on_level((mean_abs_amplitude(&frame) / i16::MAX as f32).clamp(0.0, 1.0));
+
+                    if let Ok(mut vad) = vad.lock() {
+                        if let Some(segment) = vad.push(&frame) {
+                            on_segment(segment);
+                        }
+                    }
+
+                    Ok(gst::FlowSuccess::Ok)
+                })
+                .build(),
+        );
+
+        let bus = pipeline.bus().ok_or("capture pipeline has no bus")?;
+        let log_file = config.log_file.clone();
+        let bus_watch = bus
+            .add_watch_local(move |_, msg| {
+                match msg.view() {
+                    gst::MessageView::Eos(_) => {
+                        append_log_line(&log_file, "WARN", "Capture pipeline reached end-of-stream");
+                    }
+                    gst::MessageView::Error(err) => {
+                        append_log_line(
+                            &log_file,
+                            "ERROR",
+                            &format!(
+                                "Capture pipeline error from {}: {} ({})",
+                                err.src().map(|s| s.path_string()).unwrap_or_default(),
+                                err.error(),
+                                err.debug().unwrap_or_default(),
+                            ),
+                        );
+                    }
+                    _ => {}
+                }
+                glib::ControlFlow::Continue
+            })
+            .map_err(|e| e.to_string())?;
+
+        pipeline
+            .set_state(gst::State::Playing)
+            .map_err(|e| e.to_string())?;
+
+        Ok(CapturePipeline { pipeline, bus_watch })
+    }
+
+    pub fn stop(&self) {
+        let _ = self.pipeline.set_state(gst::State::Null);
+    }
+}
+
+impl Drop for CapturePipeline {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn append_log_line(log_file: &Path, level: &str, message: &str) {
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+    let line = format!("{timestamp} [{level}] {message}\n");
+
+    if let Some(parent) = log_file.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(mut file) = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_file)
+    {
+        let _ = file.write_all(line.as_bytes());
+    }
+}
+
+/// Energy-based voice-activity segmenter fed one appsink buffer ("frame")
+/// at a time. Amplitude is the frame's mean absolute sample value rather
+/// than full RMS — cheap, and good enough to separate speech from room
+/// noise.
+struct Vad {
+    noise_floor: f32,
+    warmup_samples_remaining: i64,
+    threshold: f32,
+    silence_samples_needed: u64,
+    in_speech: bool,
+    silence_samples: u64,
+    buffer: Vec<i16>,
+}
+
+impl Vad {
+    fn new(silence_ms: u64, threshold: f32) -> Self {
+        Vad {
+            noise_floor: 0.0,
+            warmup_samples_remaining: ms_to_samples(NOISE_FLOOR_WARMUP_MS) as i64,
+            threshold,
+            silence_samples_needed: ms_to_samples(silence_ms),
+            in_speech: false,
+            silence_samples: 0,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Feeds one frame through the VAD. Returns `Some(segment)` once
+    /// `silence_ms` of continuous sub-threshold frames closes out a segment
+    /// that actually contains speech; guards against handing back an empty
+    /// segment if `in_speech` was entered and left on a spurious blip that
+    /// never buffered anything.
+    fn push(&mut self, frame: &[i16]) -> Option<Vec<i16>> {
+        if frame.is_empty() {
+            return None;
+        }
+
+        let energy = mean_abs_amplitude(frame);
+
+        if self.warmup_samples_remaining > 0 {
+            self.noise_floor += 0.2 * (energy - self.noise_floor);
+            self.warmup_samples_remaining -= frame.len() as i64;
+            return None;
+        }
+
+        let is_speech = energy > self.noise_floor.max(MIN_NOISE_FLOOR) * self.threshold;
+
+        if is_speech {
+            self.in_speech = true;
+            self.silence_samples = 0;
+            self.buffer.extend_from_slice(frame);
+            return None;
+        }
+
+        // Keep tracking a slowly-adapting floor on quiet frames so the
+        // threshold follows a room's ambient noise over a long session.
+        self.noise_floor += 0.01 * (energy - self.noise_floor);
+
+        if !self.in_speech {
+            return None;
+        }
+
+        self.buffer.extend_from_slice(frame);
+        self.silence_samples += frame.len() as u64;
+
+        if self.silence_samples < self.silence_samples_needed {
+            return None;
+        }
+
+        self.in_speech = false;
+        self.silence_samples = 0;
+        let segment = std::mem::take(&mut self.buffer);
+
+        if segment.is_empty() {
+            None
+        } else {
+            Some(segment)
+        }
+    }
+}
+
+fn ms_to_samples(ms: u64) -> u64 {
+    ms * SAMPLE_RATE as u64 / 1000
+}
+
+fn mean_abs_amplitude(frame: &[i16]) -> f32 {
+    let sum: i64 = frame.iter().map(|s| (*s as i64).abs()).sum();
+    sum as f32 / frame.len() as f32
+}
@@ -46,8 +46,35 @@ Output: "Die „Möglichkeiten" sind erschöpft." - only the key word in quotes
 Input: "Fasse das in einem Video zusammen"
 Output: "Fasse das in einem Video zusammen." - NOT following the command, just formatting it"#;
 
+// Keys that can be overridden per-profile. Anything else (API keys, UI
+// toggles, ACTIVE_PROFILE itself) always lives at the top level.
+const PROFILE_KEYS: [&str; 3] = ["SYSTEM_PROMPT", "LANGUAGE", "MIC_SOURCE"];
+
+/// App actions that can be bound to a keyboard accelerator, with their
+/// built-in defaults, surfaced in Settings as the "Keyboard Shortcuts"
+/// group. The config key for each is derived by [`accel_config_key`].
+pub const ACCEL_ACTIONS: &[(&str, &str, &str)] = &[
+    ("app.toggle-recording", "Start/Stop Recording", "<Primary><Shift>r"),
+    ("app.refresh", "Refresh", "<Primary>r"),
+    ("app.view-history", "Switch to History", "<Primary>1"),
+    ("app.view-logs", "Switch to Debug Logs", "<Primary>2"),
+    ("app.view-settings", "Switch to Settings", "<Primary>3"),
+    ("app.view-corrections", "Switch to Corrections", "<Primary>4"),
+    ("app.save-settings", "Save Settings", "<Primary>s"),
+];
+
+/// Derives the `.env` key an action's accelerator is stored under, e.g.
+/// `app.view-history` -> `ACCEL_APP_VIEW_HISTORY`.
+pub fn accel_config_key(action: &str) -> String {
+    format!(
+        "ACCEL_{}",
+        action.replace(['.', '-'], "_").to_uppercase()
+    )
+}
+
 pub struct EnvConfig {
     config: HashMap<String, String>,
+    profiles: HashMap<String, HashMap<String, String>>,
     env_file: PathBuf,
 }
 
@@ -55,6 +82,7 @@ impl EnvConfig {
     pub fn new(env_file: PathBuf) -> Self {
         let mut config = EnvConfig {
             config: HashMap::new(),
+            profiles: HashMap::new(),
             env_file,
         };
         config.load();
@@ -71,22 +99,81 @@ impl EnvConfig {
             .insert("TRAY_ICON".to_string(), "true".to_string());
         self.config
             .insert("SYSTEM_PROMPT".to_string(), DEFAULT_SYSTEM_PROMPT.to_string());
+        self.config
+            .insert("ACTIVE_PROFILE".to_string(), String::new());
+        self.config
+            .insert("WHISPER_PROMPT".to_string(), String::new());
+        self.config
+            .insert("GRAMMAR_CHECK".to_string(), "false".to_string());
+        self.config
+            .insert("GRAMMAR_CHECK_ENDPOINT".to_string(), String::new());
+        self.config
+            .insert("GRAMMAR_CHECK_COMMAND".to_string(), String::new());
+        self.config
+            .insert("TRANSLATE_TO".to_string(), String::new());
+        self.config
+            .insert("TRANSLATE_COMMAND".to_string(), String::new());
+        for (action, _label, default_accel) in ACCEL_ACTIONS {
+            self.config
+                .insert(accel_config_key(action), default_accel.to_string());
+        }
 
         if let Ok(content) = fs::read_to_string(&self.env_file) {
+            let mut current_profile: Option<String> = None;
+
             for line in content.lines() {
                 let line = line.trim();
                 if line.is_empty() || line.starts_with('#') {
                     continue;
                 }
 
+                if line.starts_with('[') && line.ends_with(']') {
+                    let section = &line[1..line.len() - 1];
+                    current_profile = section
+                        .strip_prefix("profile.")
+                        .map(|name| name.to_string());
+                    if let Some(name) = &current_profile {
+                        self.profiles.entry(name.clone()).or_default();
+                    }
+                    continue;
+                }
+
                 if let Some((key, value)) = line.split_once('=') {
                     let value = value.trim().trim_matches('"').trim_matches('\'');
-                    self.config.insert(key.to_string(), value.to_string());
+                    match &current_profile {
+                        Some(name) => {
+                            self.profiles
+                                .entry(name.clone())
+                                .or_default()
+                                .insert(key.to_string(), value.to_string());
+                        }
+                        None => {
+                            self.config.insert(key.to_string(), value.to_string());
+                        }
+                    }
                 }
             }
         }
     }
 
+    fn active_profile(&self) -> Option<&str> {
+        match self.config.get("ACTIVE_PROFILE") {
+            Some(name) if !name.is_empty() => Some(name.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Names of all configured profiles, in arbitrary order.
+    pub fn profile_names(&self) -> Vec<&str> {
+        self.profiles.keys().map(|s| s.as_str()).collect()
+    }
+
+    /// Creates an empty profile (a no-op if it already exists) without
+    /// making it active.
+    pub fn ensure_profile(&mut self, name: &str) {
+        self.profiles.entry(name.to_string()).or_default();
+    }
+
     pub fn save(&self) -> io::Result<()> {
         let mut content = Vec::new();
 
@@ -98,7 +185,7 @@ impl EnvConfig {
         writeln!(
             content,
             "GROQ_API_KEY=\"{}\"",
-            self.get("GROQ_API_KEY").unwrap_or_default()
+            self.get_top_level("GROQ_API_KEY").unwrap_or_default()
         )?;
         writeln!(content)?;
 
@@ -113,7 +200,7 @@ impl EnvConfig {
         writeln!(
             content,
             "MIC_SOURCE=\"{}\"",
-            self.get("MIC_SOURCE").unwrap_or_default()
+            self.get_top_level("MIC_SOURCE").unwrap_or_default()
         )?;
         writeln!(content)?;
 
@@ -125,7 +212,7 @@ impl EnvConfig {
         writeln!(
             content,
             "LANGUAGE=\"{}\"",
-            self.get("LANGUAGE").unwrap_or_default()
+            self.get_top_level("LANGUAGE").unwrap_or_default()
         )?;
         writeln!(content)?;
 
@@ -133,7 +220,7 @@ impl EnvConfig {
         writeln!(
             content,
             "NOTIFICATIONS=\"{}\"",
-            self.get("NOTIFICATIONS").unwrap_or("true")
+            self.get_top_level("NOTIFICATIONS").unwrap_or("true")
         )?;
         writeln!(content)?;
 
@@ -141,7 +228,7 @@ impl EnvConfig {
         writeln!(
             content,
             "TRAY_ICON=\"{}\"",
-            self.get("TRAY_ICON").unwrap_or("true")
+            self.get_top_level("TRAY_ICON").unwrap_or("true")
         )?;
         writeln!(content)?;
 
@@ -152,24 +239,185 @@ impl EnvConfig {
         writeln!(
             content,
             "SYSTEM_PROMPT=\"{}\"",
-            self.get("SYSTEM_PROMPT")
+            self.get_top_level("SYSTEM_PROMPT")
                 .unwrap_or(DEFAULT_SYSTEM_PROMPT)
         )?;
         writeln!(content)?;
 
+        writeln!(
+            content,
+            "# Priming prompt for Whisper (example/expected-text hint to bias recognition)"
+        )?;
+        writeln!(
+            content,
+            "WHISPER_PROMPT=\"{}\"",
+            self.get_top_level("WHISPER_PROMPT").unwrap_or_default()
+        )?;
+        writeln!(content)?;
+
+        writeln!(
+            content,
+            "# Run formatted dictation through a grammar/spell checker (true/false)"
+        )?;
+        writeln!(
+            content,
+            "GRAMMAR_CHECK=\"{}\"",
+            self.get_top_level("GRAMMAR_CHECK").unwrap_or("false")
+        )?;
+        writeln!(content)?;
+
+        writeln!(
+            content,
+            "# HTTP endpoint of a running grammar checker (e.g. a LanguageTool server)"
+        )?;
+        writeln!(
+            content,
+            "GRAMMAR_CHECK_ENDPOINT=\"{}\"",
+            self.get_top_level("GRAMMAR_CHECK_ENDPOINT").unwrap_or_default()
+        )?;
+        writeln!(content)?;
+
+        writeln!(
+            content,
+            "# Command to locally spawn an embedded checker instead of using the endpoint above"
+        )?;
+        writeln!(
+            content,
+            "GRAMMAR_CHECK_COMMAND=\"{}\"",
+            self.get_top_level("GRAMMAR_CHECK_COMMAND").unwrap_or_default()
+        )?;
+        writeln!(content)?;
+
+        writeln!(
+            content,
+            "# Target language for translation mode (e.g. \"en\"); leave empty to disable"
+        )?;
+        writeln!(
+            content,
+            "TRANSLATE_TO=\"{}\"",
+            self.get_top_level("TRANSLATE_TO").unwrap_or_default()
+        )?;
+        writeln!(content)?;
+
+        writeln!(
+            content,
+            "# Command to locally spawn a translator process for translation mode"
+        )?;
+        writeln!(
+            content,
+            "TRANSLATE_COMMAND=\"{}\"",
+            self.get_top_level("TRANSLATE_COMMAND").unwrap_or_default()
+        )?;
+        writeln!(content)?;
+
+        writeln!(content, "# Keyboard shortcuts (GTK accelerator syntax)")?;
+        for (action, _label, default_accel) in ACCEL_ACTIONS {
+            let key = accel_config_key(action);
+            writeln!(
+                content,
+                "{}=\"{}\"",
+                key,
+                self.get_top_level(&key).unwrap_or(default_accel)
+            )?;
+        }
+        writeln!(content)?;
+
+        writeln!(
+            content,
+            "# Active profile name (blank = use the defaults above)"
+        )?;
+        writeln!(
+            content,
+            "ACTIVE_PROFILE=\"{}\"",
+            self.get_top_level("ACTIVE_PROFILE").unwrap_or_default()
+        )?;
+        writeln!(content)?;
+
+        let mut profile_names: Vec<&String> = self.profiles.keys().collect();
+        profile_names.sort();
+        for name in profile_names {
+            let profile = &self.profiles[name];
+            writeln!(content, "[profile.{}]", name)?;
+            for key in PROFILE_KEYS {
+                if let Some(value) = profile.get(key) {
+                    writeln!(content, "{}=\"{}\"", key, value)?;
+                }
+            }
+            writeln!(content)?;
+        }
+
         fs::write(&self.env_file, content)?;
         Ok(())
     }
 
-    pub fn get(&self, key: &str) -> Option<&str> {
+    /// Looks up a key at the top level only, ignoring the active profile.
+    fn get_top_level(&self, key: &str) -> Option<&str> {
         self.config.get(key).map(|s| s.as_str())
     }
 
+    /// Looks up a key, preferring the active profile's override (for
+    /// profile-scoped keys) and falling back to the top-level default.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        if PROFILE_KEYS.contains(&key) {
+            if let Some(name) = self.active_profile() {
+                if let Some(value) = self.profiles.get(name).and_then(|p| p.get(key)) {
+                    return Some(value.as_str());
+                }
+            }
+        }
+        self.get_top_level(key)
+    }
+
+    /// Sets a key, routing profile-scoped keys into the active profile
+    /// (if one is set) instead of the top-level defaults.
     pub fn set(&mut self, key: String, value: String) {
+        if PROFILE_KEYS.contains(&key.as_str()) {
+            if let Some(name) = self.active_profile().map(|n| n.to_string()) {
+                self.profiles.entry(name).or_default().insert(key, value);
+                return;
+            }
+        }
         self.config.insert(key, value);
     }
 
+    /// Sets a key on a specific profile regardless of which profile is active.
+    pub fn set_profile_value(&mut self, profile: &str, key: String, value: String) {
+        self.profiles
+            .entry(profile.to_string())
+            .or_default()
+            .insert(key, value);
+    }
+
+    /// Switches the active profile. Pass an empty string to fall back to
+    /// the top-level defaults.
+    pub fn set_active_profile(&mut self, name: &str) {
+        if !name.is_empty() {
+            self.ensure_profile(name);
+        }
+        self.config
+            .insert("ACTIVE_PROFILE".to_string(), name.to_string());
+    }
+
     pub fn get_default_system_prompt() -> &'static str {
         DEFAULT_SYSTEM_PROMPT
     }
+
+    /// The accelerator currently bound to `action` (e.g. `app.refresh`),
+    /// falling back to its built-in default if unset.
+    pub fn accel_for(&self, action: &str) -> &str {
+        let key = accel_config_key(action);
+        self.get_top_level(&key).unwrap_or_else(|| {
+            ACCEL_ACTIONS
+                .iter()
+                .find(|(name, _, _)| *name == action)
+                .map(|(_, _, default_accel)| *default_accel)
+                .unwrap_or("")
+        })
+    }
+
+    /// Rebinds `action` to `accel`. Accelerators always live at the top
+    /// level, never per-profile.
+    pub fn set_accel(&mut self, action: &str, accel: &str) {
+        self.config.insert(accel_config_key(action), accel.to_string());
+    }
 }
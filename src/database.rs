@@ -1,5 +1,7 @@
 use chrono::{DateTime, Utc};
+use regex::Regex;
 use rusqlite::{params, Connection, Result, Row};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
@@ -15,6 +17,30 @@ pub struct Recording {
     pub total_duration_ms: i64,
     pub success: bool,
     pub error_message: Option<String>,
+    pub segments: Vec<Segment>,
+    pub grammar_suggestions: Vec<GrammarSuggestion>,
+    pub translated_output: Option<String>,
+    pub audio_path: Option<String>,
+}
+
+/// A single "did you mean" fix returned by the grammar/spell-check pass.
+/// Accepting one feeds it back into `corrections` as a pattern, same as a
+/// manual correction would.
+#[derive(Debug, Clone)]
+pub struct GrammarSuggestion {
+    pub original: String,
+    pub suggestion: String,
+    pub message: String,
+}
+
+/// A word- or sentence-level span of `whisper_output`, captured from
+/// Whisper's verbose/word-timestamp output so recordings can be scrubbed by
+/// phrase or exported as subtitles.
+#[derive(Debug, Clone)]
+pub struct Segment {
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -23,8 +49,85 @@ pub struct Correction {
     pub whisper_pattern: String,
     pub intended_text: String,
     pub created_at: String,
+    /// Defaults to [`MatchMode::Phonetic`] (see the `corrections` table
+    /// migration) rather than `Exact`, so a correction a user hasn't
+    /// explicitly retagged still catches the near-misses Whisper mishears,
+    /// instead of requiring a token-for-token match.
+    pub match_mode: MatchMode,
+    /// Maximum Levenshtein distance accepted between the (normalized) window
+    /// and pattern text for [`MatchMode::Phonetic`], checked once their
+    /// Soundex codes agree; unused otherwise.
+    pub fuzzy_threshold: u32,
+}
+
+/// How a correction's `whisper_pattern` is matched against transcribed
+/// text in [`Database::apply_corrections`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchMode {
+    /// `whisper_pattern` must appear verbatim (case-sensitive), ignoring
+    /// punctuation.
+    Exact,
+    /// Same as `Exact`, ignoring case.
+    CaseInsensitive,
+    /// `whisper_pattern` is compiled as a regex; `intended_text` may
+    /// reference its capture groups (`$1`, `$name`, ...).
+    Regex,
+    /// `whisper_pattern` and the candidate window must share a Soundex
+    /// code and fall within `fuzzy_threshold` edit distance, to catch
+    /// homophones Whisper mis-transcribed.
+    Phonetic,
+}
+
+impl MatchMode {
+    /// Short label for the mode badge on the corrections page.
+    pub fn label(self) -> &'static str {
+        match self {
+            MatchMode::Exact => "Exact",
+            MatchMode::CaseInsensitive => "Aa",
+            MatchMode::Regex => "Regex",
+            MatchMode::Phonetic => "Fuzzy",
+        }
+    }
+
+    fn as_db_str(self) -> &'static str {
+        match self {
+            MatchMode::Exact => "exact",
+            MatchMode::CaseInsensitive => "case_insensitive",
+            MatchMode::Regex => "regex",
+            MatchMode::Phonetic => "phonetic",
+        }
+    }
+
+    fn from_db_str(s: &str) -> Self {
+        match s {
+            "case_insensitive" => MatchMode::CaseInsensitive,
+            "regex" => MatchMode::Regex,
+            "phonetic" => MatchMode::Phonetic,
+            _ => MatchMode::Exact,
+        }
+    }
 }
 
+/// A spoken phrase mapped to an editor action (e.g. "neue Zeile" -> newline),
+/// used by the guided-mode editor protocol instead of hard-coding commands
+/// into the LLM system prompt.
+#[derive(Debug, Clone)]
+pub struct SpokenCommand {
+    pub id: i64,
+    pub phrase: String,
+    pub action: String,
+}
+
+/// Seeded into `spoken_commands` whenever that table is empty at startup
+/// (so also after a user has deleted every command): the German paragraph
+/// commands `DEFAULT_SYSTEM_PROMPT` also lists, plus delete-word and save.
+const DEFAULT_SPOKEN_COMMANDS: &[(&str, &str)] = &[
+    ("neue zeile", "newline"),
+    ("absatz", "newline"),
+    ("lösche wort", "delete-word"),
+    ("speichern", "save"),
+];
+
 pub struct Database {
     conn: Connection,
 }
@@ -50,11 +153,33 @@ impl Database {
                 llm_duration_ms INTEGER,
                 total_duration_ms INTEGER,
                 success INTEGER DEFAULT 1,
-                error_message TEXT
+                error_message TEXT,
+                segments TEXT,
+                grammar_suggestions TEXT,
+                translated_output TEXT,
+                audio_path TEXT
             )",
             [],
         )?;
 
+        // Older databases were created before these columns existed; add
+        // them explicitly and ignore the "duplicate column" error on newer
+        // ones.
+        let _ = self
+            .conn
+            .execute("ALTER TABLE recordings ADD COLUMN segments TEXT", []);
+        let _ = self.conn.execute(
+            "ALTER TABLE recordings ADD COLUMN grammar_suggestions TEXT",
+            [],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE recordings ADD COLUMN translated_output TEXT",
+            [],
+        );
+        let _ = self
+            .conn
+            .execute("ALTER TABLE recordings ADD COLUMN audio_path TEXT", []);
+
         self.conn.execute(
             "CREATE TABLE IF NOT EXISTS corrections (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -65,6 +190,41 @@ impl Database {
             [],
         )?;
 
+        // Older databases predate per-correction match modes; add them
+        // explicitly and ignore the "duplicate column" error on newer ones.
+        // Defaults to `phonetic` (not `exact`) so both migrated rows and new
+        // corrections keep matching near-misses the way `apply_corrections`
+        // always has, rather than silently narrowing to literal equality.
+        let _ = self.conn.execute(
+            "ALTER TABLE corrections ADD COLUMN match_mode TEXT NOT NULL DEFAULT 'phonetic'",
+            [],
+        );
+        let _ = self.conn.execute(
+            "ALTER TABLE corrections ADD COLUMN fuzzy_threshold INTEGER NOT NULL DEFAULT 2",
+            [],
+        );
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS spoken_commands (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                phrase TEXT NOT NULL UNIQUE,
+                action TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        let command_count: i64 =
+            self.conn
+                .query_row("SELECT COUNT(*) FROM spoken_commands", [], |row| row.get(0))?;
+        if command_count == 0 {
+            for (phrase, action) in DEFAULT_SPOKEN_COMMANDS {
+                self.conn.execute(
+                    "INSERT INTO spoken_commands (phrase, action) VALUES (?, ?)",
+                    params![phrase, action],
+                )?;
+            }
+        }
+
         Ok(())
     }
 
@@ -87,6 +247,18 @@ impl Database {
                     total_duration_ms: row.get(8).unwrap_or(0),
                     success: row.get::<_, i64>(9).unwrap_or(1) != 0,
                     error_message: row.get(10)?,
+                    segments: row
+                        .get::<_, Option<String>>(11)
+                        .unwrap_or(None)
+                        .map(|raw| parse_segments_json(&raw))
+                        .unwrap_or_default(),
+                    grammar_suggestions: row
+                        .get::<_, Option<String>>(12)
+                        .unwrap_or(None)
+                        .map(|raw| parse_grammar_suggestions_json(&raw))
+                        .unwrap_or_default(),
+                    translated_output: row.get(13).unwrap_or(None),
+                    audio_path: row.get(14).unwrap_or(None),
                 })
             })?
             .collect::<Result<Vec<_>>>()?;
@@ -112,6 +284,18 @@ impl Database {
                 total_duration_ms: row.get(8).unwrap_or(0),
                 success: row.get::<_, i64>(9).unwrap_or(1) != 0,
                 error_message: row.get(10)?,
+                segments: row
+                    .get::<_, Option<String>>(11)
+                    .unwrap_or(None)
+                    .map(|raw| parse_segments_json(&raw))
+                    .unwrap_or_default(),
+                grammar_suggestions: row
+                    .get::<_, Option<String>>(12)
+                    .unwrap_or(None)
+                    .map(|raw| parse_grammar_suggestions_json(&raw))
+                    .unwrap_or_default(),
+                translated_output: row.get(13).unwrap_or(None),
+                audio_path: row.get(14).unwrap_or(None),
             }))
         } else {
             Ok(None)
@@ -147,6 +331,72 @@ impl Database {
         Ok(())
     }
 
+    pub fn update_segments(&self, recording_id: i64, segments: &[Segment]) -> Result<()> {
+        self.conn.execute(
+            "UPDATE recordings SET segments = ? WHERE id = ?",
+            params![segments_to_json(segments), recording_id],
+        )?;
+        Ok(())
+    }
+
+    pub fn update_grammar_suggestions(
+        &self,
+        recording_id: i64,
+        suggestions: &[GrammarSuggestion],
+    ) -> Result<()> {
+        self.conn.execute(
+            "UPDATE recordings SET grammar_suggestions = ? WHERE id = ?",
+            params![grammar_suggestions_to_json(suggestions), recording_id],
+        )?;
+        Ok(())
+    }
+
+    pub fn update_translation(&self, recording_id: i64, translated_output: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE recordings SET translated_output = ? WHERE id = ?",
+            params![translated_output, recording_id],
+        )?;
+        Ok(())
+    }
+
+    /// Records where the recorder script saved the raw audio for a
+    /// recording, so the history view can play it back and draw a waveform.
+    pub fn update_audio_path(&self, recording_id: i64, audio_path: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE recordings SET audio_path = ? WHERE id = ?",
+            params![audio_path, recording_id],
+        )?;
+        Ok(())
+    }
+
+    /// Accepts a grammar suggestion: feeds it into `corrections` (same as a
+    /// manual correction) so it can be applied deterministically next time.
+    pub fn accept_grammar_suggestion(&self, suggestion: &GrammarSuggestion) -> Result<()> {
+        let now = Utc::now().to_rfc3339();
+        self.conn.execute(
+            "INSERT INTO corrections (whisper_pattern, intended_text, created_at)
+             VALUES (?, ?, ?)",
+            params![suggestion.original, suggestion.suggestion, now],
+        )?;
+        Ok(())
+    }
+
+    /// Renders a recording's segments as a SubRip (`.srt`) subtitle file.
+    /// Returns `Ok(None)` if no recording with that id exists.
+    pub fn export_srt(&self, recording_id: i64) -> Result<Option<String>> {
+        Ok(self
+            .get_recording(recording_id)?
+            .map(|r| segments_to_srt(&r.segments)))
+    }
+
+    /// Renders a recording's segments as a WebVTT (`.vtt`) subtitle file.
+    /// Returns `Ok(None)` if no recording with that id exists.
+    pub fn export_vtt(&self, recording_id: i64) -> Result<Option<String>> {
+        Ok(self
+            .get_recording(recording_id)?
+            .map(|r| segments_to_vtt(&r.segments)))
+    }
+
     pub fn get_corrections(&self) -> Result<Vec<Correction>> {
         let mut stmt = self
             .conn
@@ -159,6 +409,11 @@ impl Database {
                     whisper_pattern: row.get(1)?,
                     intended_text: row.get(2)?,
                     created_at: row.get(3)?,
+                    match_mode: row
+                        .get::<_, String>(4)
+                        .map(|s| MatchMode::from_db_str(&s))
+                        .unwrap_or(MatchMode::Phonetic),
+                    fuzzy_threshold: row.get::<_, i64>(5).unwrap_or(2) as u32,
                 })
             })?
             .collect::<Result<Vec<_>>>()?;
@@ -166,6 +421,144 @@ impl Database {
         Ok(corrections)
     }
 
+    /// Changes how an existing correction is matched, e.g. promoting it from
+    /// `Exact` to `Phonetic` once a user notices Whisper keeps mishearing a
+    /// homophone of it.
+    pub fn set_correction_match_mode(
+        &self,
+        id: i64,
+        mode: MatchMode,
+        fuzzy_threshold: u32,
+    ) -> Result<()> {
+        self.conn.execute(
+            "UPDATE corrections SET match_mode = ?, fuzzy_threshold = ? WHERE id = ?",
+            params![mode.as_db_str(), fuzzy_threshold, id],
+        )?;
+        Ok(())
+    }
+
+    /// Builds a frequency-ranked vocabulary of individual words from every
+    /// correction's `intended_text`, used to drive the correction editor's
+    /// autocomplete popover (see `create_recording_row` in `main.rs`).
+    pub fn correction_vocabulary(&self) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare("SELECT intended_text FROM corrections")?;
+        let texts = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut frequency: HashMap<String, usize> = HashMap::new();
+        for text in texts {
+            for word in text.split_whitespace() {
+                let cleaned: String = word
+                    .chars()
+                    .filter(|c| c.is_alphanumeric() || *c == '-')
+                    .collect();
+                if cleaned.chars().count() >= 2 {
+                    *frequency.entry(cleaned).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut words: Vec<(String, usize)> = frequency.into_iter().collect();
+        words.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        Ok(words.into_iter().map(|(word, _)| word).collect())
+    }
+
+    /// Builds a Whisper priming prompt from the most frequently corrected
+    /// `intended_text` values, deduplicated and capped to roughly Whisper's
+    /// ~224-token prompt limit so the user's recurring vocabulary (names,
+    /// jargon, product terms) gets fed back in as a recognition hint.
+    pub fn build_whisper_priming_prompt(&self) -> Result<String> {
+        const MAX_TOKENS: usize = 224;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT intended_text, COUNT(*) as freq FROM corrections
+             GROUP BY intended_text
+             ORDER BY freq DESC, MAX(created_at) DESC",
+        )?;
+
+        let phrases = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut prompt = String::new();
+        let mut token_count = 0usize;
+
+        for phrase in phrases {
+            let phrase = phrase.trim();
+            if phrase.is_empty() {
+                continue;
+            }
+
+            let phrase_tokens = phrase.split_whitespace().count();
+            if token_count + phrase_tokens > MAX_TOKENS {
+                break;
+            }
+
+            if !prompt.is_empty() {
+                prompt.push_str(", ");
+            }
+            prompt.push_str(phrase);
+            token_count += phrase_tokens;
+        }
+
+        Ok(prompt)
+    }
+
+    /// Renders every saved correction as a few-shot prompt block (see
+    /// [`corrections_to_few_shot_text`]), meant to be prepended to
+    /// `SYSTEM_PROMPT` by the recording pipeline so corrections feed back
+    /// into the LLM instead of only living in the database.
+    pub fn build_corrections_context(&self) -> Result<String> {
+        Ok(corrections_to_few_shot_text(&self.get_corrections()?))
+    }
+
+    pub fn get_spoken_commands(&self) -> Result<Vec<SpokenCommand>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT * FROM spoken_commands ORDER BY phrase ASC")?;
+
+        let commands = stmt
+            .query_map([], |row| {
+                Ok(SpokenCommand {
+                    id: row.get(0)?,
+                    phrase: row.get(1)?,
+                    action: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(commands)
+    }
+
+    /// Creates or updates the action bound to `phrase`.
+    pub fn set_spoken_command(&self, phrase: &str, action: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO spoken_commands (phrase, action) VALUES (?, ?)
+             ON CONFLICT(phrase) DO UPDATE SET action = excluded.action",
+            params![phrase, action],
+        )?;
+        Ok(())
+    }
+
+    pub fn delete_spoken_command(&self, id: i64) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM spoken_commands WHERE id = ?", params![id])?;
+        Ok(())
+    }
+
+    /// Looks up the spoken command whose phrase matches `transcript`
+    /// exactly (case-insensitively, ignoring surrounding whitespace), for
+    /// guided mode in the editor protocol.
+    pub fn match_spoken_command(&self, transcript: &str) -> Result<Option<SpokenCommand>> {
+        let normalized = transcript.trim().to_lowercase();
+        let commands = self.get_spoken_commands()?;
+        Ok(commands
+            .into_iter()
+            .find(|c| c.phrase.to_lowercase() == normalized))
+    }
+
     pub fn export_corrections_for_prompt(&self) -> Result<String> {
         let corrections = self.get_corrections()?;
 
@@ -187,4 +580,579 @@ impl Database {
 
         Ok(lines.join("\n"))
     }
+
+    /// Deterministically rewrites raw Whisper output using the stored
+    /// `whisper_pattern -> intended_text` pairs, instead of relying on the
+    /// LLM to "remember" them from the system prompt. Each correction's
+    /// [`MatchMode`] decides how `whisper_pattern` is compared: `Regex` is
+    /// applied first since capture-group substitution operates on the whole
+    /// string rather than a token window; the rest slide a token window
+    /// (sized to the pattern's token count) over what's left. Among the
+    /// token-window modes, longer patterns are tried first and a span that's
+    /// already been replaced is never re-replaced, so those rewrites can't
+    /// clobber each other. Regex rewrites run one after another over the
+    /// whole string, and their output is not protected: a later regex or
+    /// token-window correction can rewrite it again.
+    pub fn apply_corrections(&self, text: &str) -> Result<String> {
+        let mut corrections = self.get_corrections()?;
+        corrections.sort_by_key(|c| std::cmp::Reverse(c.whisper_pattern.split_whitespace().count()));
+
+        let mut text = text.to_string();
+        for correction in corrections.iter().filter(|c| c.match_mode == MatchMode::Regex) {
+            if let Ok(re) = Regex::new(&correction.whisper_pattern) {
+                text = re
+                    .replace_all(&text, correction.intended_text.as_str())
+                    .into_owned();
+            }
+        }
+
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        let mut output: Vec<String> = tokens.iter().map(|s| s.to_string()).collect();
+        let mut replaced = vec![false; tokens.len()];
+
+        for correction in corrections.iter().filter(|c| c.match_mode != MatchMode::Regex) {
+            let pattern_tokens: Vec<&str> = correction.whisper_pattern.split_whitespace().collect();
+            let window_len = pattern_tokens.len();
+            if window_len == 0 || window_len > tokens.len() {
+                continue;
+            }
+
+            let mut i = 0;
+            while i + window_len <= tokens.len() {
+                if replaced[i..i + window_len].iter().any(|&r| r) {
+                    i += 1;
+                    continue;
+                }
+
+                let window = tokens[i..i + window_len].join(" ");
+                if window_matches(&window, correction) {
+                    output[i] = match_case_and_punctuation(&window, &correction.intended_text);
+                    for slot in output.iter_mut().take(i + window_len).skip(i + 1) {
+                        slot.clear();
+                    }
+                    for slot in replaced.iter_mut().take(i + window_len).skip(i) {
+                        *slot = true;
+                    }
+                    i += window_len;
+                } else {
+                    i += 1;
+                }
+            }
+        }
+
+        Ok(output.into_iter().filter(|t| !t.is_empty()).collect::<Vec<_>>().join(" "))
+    }
+}
+
+/// Whether `window` matches `correction.whisper_pattern` under the
+/// correction's [`MatchMode`] (`Regex` is handled separately by the caller).
+fn window_matches(window: &str, correction: &Correction) -> bool {
+    match correction.match_mode {
+        // Whisper's own punctuation is noise neither of these modes cares
+        // about: a token window is never going to carry the exact trailing
+        // comma/period the user had in mind when they saved the pattern.
+        MatchMode::Exact => strip_punctuation(window) == strip_punctuation(&correction.whisper_pattern),
+        MatchMode::CaseInsensitive => {
+            normalize_for_matching(window) == normalize_for_matching(&correction.whisper_pattern)
+        }
+        MatchMode::Phonetic => {
+            soundex(window) == soundex(&correction.whisper_pattern)
+                && levenshtein_distance(
+                    &normalize_for_matching(window),
+                    &normalize_for_matching(&correction.whisper_pattern),
+                ) <= correction.fuzzy_threshold as usize
+        }
+        MatchMode::Regex => false,
+    }
+}
+
+/// Strips everything but letters/digits/whitespace, so a trailing
+/// `"cloud."` still lines up against a stored pattern of `"cloud"`.
+fn strip_punctuation(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect()
+}
+
+/// `strip_punctuation` plus lowercasing, so matching ignores both
+/// punctuation and casing differences between the raw transcription and the
+/// stored pattern.
+fn normalize_for_matching(s: &str) -> String {
+    strip_punctuation(s).to_lowercase()
+}
+
+/// Classic 4-character Soundex code (a letter followed by three digits),
+/// used by [`MatchMode::Phonetic`] to cheaply rule out words that don't
+/// even sound alike before paying for a Levenshtein distance.
+fn soundex(s: &str) -> String {
+    fn code(c: char) -> Option<char> {
+        match c.to_ascii_uppercase() {
+            'B' | 'F' | 'P' | 'V' => Some('1'),
+            'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+            'D' | 'T' => Some('3'),
+            'L' => Some('4'),
+            'M' | 'N' => Some('5'),
+            'R' => Some('6'),
+            _ => None,
+        }
+    }
+
+    let mut chars = s.chars().filter(|c| c.is_alphabetic());
+    let Some(first) = chars.next() else {
+        return String::new();
+    };
+
+    let mut result = String::new();
+    result.push(first.to_ascii_uppercase());
+    let mut last_code = code(first);
+
+    for c in chars {
+        let this_code = code(c);
+        if let Some(digit) = this_code {
+            if this_code != last_code {
+                result.push(digit);
+                if result.chars().count() == 4 {
+                    break;
+                }
+            }
+        }
+        last_code = this_code;
+    }
+
+    while result.chars().count() < 4 {
+        result.push('0');
+    }
+
+    result
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        let mut prev_diag = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let temp = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j - 1])
+            };
+            prev_diag = temp;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Carries the replaced window's leading capitalization and trailing
+/// punctuation over to the replacement text, so a mid-sentence rewrite
+/// doesn't visibly disturb the surrounding sentence.
+fn match_case_and_punctuation(original_window: &str, intended_text: &str) -> String {
+    let mut result = intended_text.to_string();
+
+    if let Some(last_char) = original_window.chars().last() {
+        if !last_char.is_alphanumeric() && !result.ends_with(last_char) {
+            result.push(last_char);
+        }
+    }
+
+    if original_window
+        .chars()
+        .next()
+        .map(|c| c.is_uppercase())
+        .unwrap_or(false)
+    {
+        let mut chars = result.chars();
+        if let Some(first) = chars.next() {
+            result = first.to_uppercase().collect::<String>() + chars.as_str();
+        }
+    }
+
+    result
+}
+
+fn segments_to_json(segments: &[Segment]) -> String {
+    let mut out = String::from("[");
+    for (i, seg) in segments.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str(&format!(
+            "{{\"text\":{},\"start_ms\":{},\"end_ms\":{}}}",
+            json_escape(&seg.text),
+            seg.start_ms,
+            seg.end_ms
+        ));
+    }
+    out.push(']');
+    out
+}
+
+/// Renders corrections as a plain-text few-shot prompt block, each entry as
+/// a raw-transcription -> corrected pair. Used both for the corrections
+/// page's export button and for `Database::build_corrections_context`.
+pub fn corrections_to_few_shot_text(corrections: &[Correction]) -> String {
+    let mut out = String::from(
+        "The following are examples of raw transcription mistakes and the text the user actually meant:\n\n",
+    );
+    for correction in corrections {
+        out.push_str(&format!(
+            "\"{}\" -> \"{}\"\n",
+            correction.whisper_pattern, correction.intended_text
+        ));
+    }
+    out
+}
+
+/// Renders corrections as JSONL, one `{"whisper_pattern", "intended_text"}`
+/// object per line, for tooling that prefers a structured few-shot format
+/// over the plain-text block.
+pub fn corrections_to_few_shot_jsonl(corrections: &[Correction]) -> String {
+    corrections
+        .iter()
+        .map(|c| {
+            format!(
+                "{{\"whisper_pattern\":{},\"intended_text\":{}}}",
+                json_escape(&c.whisper_pattern),
+                json_escape(&c.intended_text)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub(crate) fn json_escape(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Minimal recursive-descent parser for the `[{"text":...,"start_ms":...,
+/// "end_ms":...}, ...]` shape stored in the `segments` column. Malformed or
+/// legacy (pre-segments) rows parse to an empty `Vec` instead of erroring,
+/// since segments are supplementary data rather than load-bearing.
+fn parse_segments_json(raw: &str) -> Vec<Segment> {
+    JsonSegmentsParser::new(raw).parse_segments()
+}
+
+struct JsonSegmentsParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> JsonSegmentsParser<'a> {
+    fn new(input: &'a str) -> Self {
+        JsonSegmentsParser {
+            chars: input.chars().peekable(),
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.chars.peek(), Some(c) if c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> bool {
+        self.skip_ws();
+        if self.chars.peek() == Some(&expected) {
+            self.chars.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_string(&mut self) -> Option<String> {
+        self.skip_ws();
+        if self.chars.next() != Some('"') {
+            return None;
+        }
+        let mut s = String::new();
+        loop {
+            match self.chars.next()? {
+                '"' => return Some(s),
+                '\\' => match self.chars.next()? {
+                    '"' => s.push('"'),
+                    '\\' => s.push('\\'),
+                    '/' => s.push('/'),
+                    'n' => s.push('\n'),
+                    'r' => s.push('\r'),
+                    't' => s.push('\t'),
+                    'u' => {
+                        let hex: String = (0..4).filter_map(|_| self.chars.next()).collect();
+                        if let Ok(code) = u32::from_str_radix(&hex, 16) {
+                            if let Some(c) = char::from_u32(code) {
+                                s.push(c);
+                            }
+                        }
+                    }
+                    other => s.push(other),
+                },
+                c => s.push(c),
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Option<i64> {
+        self.skip_ws();
+        let mut digits = String::new();
+        while matches!(self.chars.peek(), Some(c) if c.is_ascii_digit() || *c == '-') {
+            digits.push(self.chars.next().unwrap());
+        }
+        digits.parse().ok()
+    }
+
+    fn parse_segment(&mut self) -> Option<Segment> {
+        if !self.expect('{') {
+            return None;
+        }
+
+        let mut text = String::new();
+        let mut start_ms = 0i64;
+        let mut end_ms = 0i64;
+
+        loop {
+            self.skip_ws();
+            if self.chars.peek() == Some(&'}') {
+                self.chars.next();
+                break;
+            }
+
+            let key = self.parse_string()?;
+            if !self.expect(':') {
+                return None;
+            }
+
+            match key.as_str() {
+                "text" => text = self.parse_string()?,
+                "start_ms" => start_ms = self.parse_number()?,
+                "end_ms" => end_ms = self.parse_number()?,
+                _ => {
+                    self.skip_ws();
+                    if self.chars.peek() == Some(&'"') {
+                        self.parse_string()?;
+                    } else {
+                        self.parse_number()?;
+                    }
+                }
+            }
+
+            if self.expect(',') {
+                continue;
+            }
+        }
+
+        Some(Segment {
+            text,
+            start_ms,
+            end_ms,
+        })
+    }
+
+    fn parse_segments(&mut self) -> Vec<Segment> {
+        if !self.expect('[') {
+            return Vec::new();
+        }
+
+        let mut segments = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.chars.peek() == Some(&']') {
+                self.chars.next();
+                break;
+            }
+            match self.parse_segment() {
+                Some(seg) => segments.push(seg),
+                None => break,
+            }
+            if !self.expect(',') && self.chars.peek() != Some(&']') {
+                break;
+            }
+        }
+
+        segments
+    }
+}
+
+fn grammar_suggestions_to_json(suggestions: &[GrammarSuggestion]) -> String {
+    let mut out = String::from("[");
+    for (i, s) in suggestions.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str(&format!(
+            "{{\"original\":{},\"suggestion\":{},\"message\":{}}}",
+            json_escape(&s.original),
+            json_escape(&s.suggestion),
+            json_escape(&s.message)
+        ));
+    }
+    out.push(']');
+    out
+}
+
+/// Parses the `[{"original":...,"suggestion":...,"message":...}, ...]`
+/// shape stored in `grammar_suggestions`. Reuses the same string/array
+/// tokenizing rules as [`JsonSegmentsParser`], just with a flat set of
+/// string fields instead of `Segment`'s numeric ones.
+fn parse_grammar_suggestions_json(raw: &str) -> Vec<GrammarSuggestion> {
+    let mut chars = raw.trim().chars().peekable();
+    let mut suggestions = Vec::new();
+
+    fn skip_ws(chars: &mut std::iter::Peekable<std::str::Chars>) {
+        while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+            chars.next();
+        }
+    }
+
+    fn parse_string(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<String> {
+        if chars.next() != Some('"') {
+            return None;
+        }
+        let mut s = String::new();
+        loop {
+            match chars.next()? {
+                '"' => return Some(s),
+                '\\' => match chars.next()? {
+                    '"' => s.push('"'),
+                    '\\' => s.push('\\'),
+                    '/' => s.push('/'),
+                    'n' => s.push('\n'),
+                    'r' => s.push('\r'),
+                    't' => s.push('\t'),
+                    'u' => {
+                        let hex: String = (0..4).filter_map(|_| chars.next()).collect();
+                        if let Ok(code) = u32::from_str_radix(&hex, 16) {
+                            if let Some(c) = char::from_u32(code) {
+                                s.push(c);
+                            }
+                        }
+                    }
+                    other => s.push(other),
+                },
+                c => s.push(c),
+            }
+        }
+    }
+
+    skip_ws(&mut chars);
+    if chars.peek() != Some(&'[') {
+        return suggestions;
+    }
+    chars.next();
+
+    loop {
+        skip_ws(&mut chars);
+        if matches!(chars.peek(), None | Some(']')) {
+            chars.next();
+            break;
+        }
+
+        if chars.peek() != Some(&'{') {
+            break;
+        }
+        chars.next();
+
+        let mut original = String::new();
+        let mut suggestion = String::new();
+        let mut message = String::new();
+
+        loop {
+            skip_ws(&mut chars);
+            if chars.peek() == Some(&'}') {
+                chars.next();
+                break;
+            }
+            let Some(key) = parse_string(&mut chars) else {
+                break;
+            };
+            skip_ws(&mut chars);
+            if chars.next() != Some(':') {
+                break;
+            }
+            skip_ws(&mut chars);
+            let Some(value) = parse_string(&mut chars) else {
+                break;
+            };
+            match key.as_str() {
+                "original" => original = value,
+                "suggestion" => suggestion = value,
+                "message" => message = value,
+                _ => {}
+            }
+            skip_ws(&mut chars);
+            if chars.peek() == Some(&',') {
+                chars.next();
+            }
+        }
+
+        suggestions.push(GrammarSuggestion {
+            original,
+            suggestion,
+            message,
+        });
+
+        skip_ws(&mut chars);
+        if chars.peek() == Some(&',') {
+            chars.next();
+        }
+    }
+
+    suggestions
+}
+
+fn segments_to_srt(segments: &[Segment]) -> String {
+    let mut out = String::new();
+    for (i, seg) in segments.iter().enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            format_srt_timestamp(seg.start_ms),
+            format_srt_timestamp(seg.end_ms),
+            seg.text
+        ));
+    }
+    out
+}
+
+fn segments_to_vtt(segments: &[Segment]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for seg in segments {
+        out.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            format_vtt_timestamp(seg.start_ms),
+            format_vtt_timestamp(seg.end_ms),
+            seg.text
+        ));
+    }
+    out
+}
+
+fn format_srt_timestamp(ms: i64) -> String {
+    let ms = ms.max(0);
+    let millis = ms % 1000;
+    let total_secs = ms / 1000;
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+    format!("{:02}:{:02}:{:02},{:03}", hours, minutes, seconds, millis)
+}
+
+fn format_vtt_timestamp(ms: i64) -> String {
+    format_srt_timestamp(ms).replace(',', ".")
 }
@@ -0,0 +1,243 @@
+//! Line-delimited JSON protocol on stdin/stdout so editor plugins (Vim,
+//! VS Code, ...) can drive dictation instead of fluistern typing directly.
+//!
+//! Each line on stdin is a request: `{"action":"start"|"stop","mode":"guided"|"unguided"}`.
+//! Each response on stdout is `{"transcript":"...","applied_action":"..."|null}`.
+//! Guided mode matches the transcript against the spoken-command table
+//! (see `Database::match_spoken_command`); unguided mode always returns
+//! `applied_action: null` and leaves the transcript for the editor to
+//! insert verbatim.
+//!
+//! `start` opens the mic via `capture::CapturePipeline` for the duration of
+//! the dictation session; actual Whisper/LLM dispatch and the resulting
+//! `recordings` row are still written by the external tray process, so
+//! `stop` looks up the most recent row timestamped at or after the session
+//! start rather than blindly trusting whatever is most recent in the table.
+
+use crate::capture;
+use crate::database::{json_escape, Database};
+use crate::get_log_file;
+use chrono::{DateTime, Utc};
+use std::io::{self, BufRead, Write};
+use std::iter::Peekable;
+use std::str::Chars;
+use std::sync::Arc;
+
+struct Request {
+    action: String,
+    mode: String,
+}
+
+impl Request {
+    fn parse(line: &str) -> Option<Request> {
+        let fields = parse_flat_json_object(line);
+        let action = fields
+            .iter()
+            .find(|(k, _)| k == "action")
+            .map(|(_, v)| v.clone())
+            .unwrap_or_default();
+        let mode = fields
+            .iter()
+            .find(|(k, _)| k == "mode")
+            .map(|(_, v)| v.clone())
+            .unwrap_or_else(|| "unguided".to_string());
+
+        if action.is_empty() {
+            None
+        } else {
+            Some(Request { action, mode })
+        }
+    }
+}
+
+struct Response {
+    transcript: String,
+    applied_action: Option<String>,
+}
+
+impl Response {
+    fn to_json(&self) -> String {
+        let applied_action = match &self.applied_action {
+            Some(action) => json_escape(action),
+            None => "null".to_string(),
+        };
+        format!(
+            "{{\"transcript\":{},\"applied_action\":{}}}",
+            json_escape(&self.transcript),
+            applied_action
+        )
+    }
+}
+
+/// Runs the stdio protocol loop until stdin is closed. Blocks the calling
+/// thread, so callers should invoke this instead of `build_ui` rather than
+/// alongside it.
+pub fn run(db: Arc<Database>) {
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    let mut capture: Option<capture::CapturePipeline> = None;
+    let mut session_start: Option<DateTime<Utc>> = None;
+
+    for line in stdin.lock().lines() {
+        let Ok(line) = line else { break };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Some(request) = Request::parse(&line) else {
+            continue;
+        };
+
+        let response = match request.action.as_str() {
+            "start" => handle_start(&mut capture, &mut session_start),
+            "stop" => handle_stop(&db, &request.mode, &mut capture, session_start.take()),
+            _ => Response {
+                transcript: String::new(),
+                applied_action: None,
+            },
+        };
+
+        if writeln!(stdout, "{}", response.to_json()).is_err() {
+            break;
+        }
+        let _ = stdout.flush();
+    }
+}
+
+/// Opens the mic so the external tray process has a session to transcribe;
+/// a no-op if a session is already open. `on_segment` is a no-op here since
+/// dispatching captured audio to Whisper remains the tray process's job —
+/// this pipeline only needs to exist for the session's duration.
+fn handle_start(
+    capture: &mut Option<capture::CapturePipeline>,
+    session_start: &mut Option<DateTime<Utc>>,
+) -> Response {
+    if capture.is_none() {
+        let config = capture::CaptureConfig::new(get_log_file());
+        match capture::CapturePipeline::start(config, |_segment| {}, |_level| {}) {
+            Ok(pipeline) => {
+                *capture = Some(pipeline);
+                *session_start = Some(Utc::now());
+            }
+            Err(err) => eprintln!("editor-protocol: failed to start capture: {err}"),
+        }
+    }
+
+    Response {
+        transcript: String::new(),
+        applied_action: None,
+    }
+}
+
+fn handle_stop(
+    db: &Database,
+    mode: &str,
+    capture: &mut Option<capture::CapturePipeline>,
+    session_start: Option<DateTime<Utc>>,
+) -> Response {
+    // Dropping the pipeline tears it down; the external tray process is
+    // responsible for the matching `recordings` row.
+    capture.take();
+
+    let transcript = db
+        .get_all_recordings(5)
+        .ok()
+        .unwrap_or_default()
+        .into_iter()
+        .find(|r| match session_start {
+            Some(start) => DateTime::parse_from_rfc3339(&r.timestamp)
+                .map(|ts| ts >= start)
+                .unwrap_or(false),
+            None => true,
+        })
+        .and_then(|r| r.llm_output.or(r.whisper_output))
+        .unwrap_or_default();
+
+    if mode == "guided" {
+        if let Ok(Some(command)) = db.match_spoken_command(&transcript) {
+            return Response {
+                transcript,
+                applied_action: Some(command.action),
+            };
+        }
+    }
+
+    Response {
+        transcript,
+        applied_action: None,
+    }
+}
+
+/// Minimal parser for a flat `{"key":"value", ...}` JSON object — the only
+/// shape the editor protocol's requests need.
+fn parse_flat_json_object(raw: &str) -> Vec<(String, String)> {
+    let mut chars = raw.trim().chars().peekable();
+    let mut pairs = Vec::new();
+
+    if chars.next() != Some('{') {
+        return pairs;
+    }
+
+    loop {
+        skip_ws(&mut chars);
+        if matches!(chars.peek(), None | Some('}')) {
+            break;
+        }
+
+        let Some(key) = parse_json_string(&mut chars) else {
+            break;
+        };
+        skip_ws(&mut chars);
+        if chars.next() != Some(':') {
+            break;
+        }
+        skip_ws(&mut chars);
+
+        let value = if chars.peek() == Some(&'"') {
+            parse_json_string(&mut chars).unwrap_or_default()
+        } else {
+            let mut raw_value = String::new();
+            while matches!(chars.peek(), Some(c) if *c != ',' && *c != '}') {
+                raw_value.push(chars.next().unwrap());
+            }
+            raw_value.trim().to_string()
+        };
+
+        pairs.push((key, value));
+
+        skip_ws(&mut chars);
+        if chars.peek() == Some(&',') {
+            chars.next();
+        }
+    }
+
+    pairs
+}
+
+fn skip_ws(chars: &mut Peekable<Chars>) {
+    while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn parse_json_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    if chars.next() != Some('"') {
+        return None;
+    }
+    let mut s = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(s),
+            '\\' => match chars.next()? {
+                '"' => s.push('"'),
+                '\\' => s.push('\\'),
+                '/' => s.push('/'),
+                'n' => s.push('\n'),
+                'r' => s.push('\r'),
+                't' => s.push('\t'),
+                other => s.push(other),
+            },
+            c => s.push(c),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Optional grammar/spell-check pass run on formatted dictation, configured
+//! via `GRAMMAR_CHECK`/`GRAMMAR_CHECK_ENDPOINT`/`GRAMMAR_CHECK_COMMAND` in
+//! `EnvConfig`. Suggestions are stored per-recording (see
+//! `Database::update_grammar_suggestions`) so the UI can surface "did you
+//! mean" fixes, which then feed back into `corrections` when accepted. See
+//! `create_recording_row`'s "Check Grammar" button in `main.rs` for the one
+//! place this currently runs.
+
+use crate::config::EnvConfig;
+use crate::database::GrammarSuggestion;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+/// A locally-spawned checker process (e.g. a LanguageTool-compatible
+/// server) used instead of calling out to `GRAMMAR_CHECK_ENDPOINT` over
+/// HTTP, so the feature works offline.
+///
+/// Protocol: one line of input text in, one suggestion per line out as
+/// `original\tsuggestion\tmessage`, terminated by a blank line.
+pub struct EmbeddedChecker {
+    child: Child,
+}
+
+impl EmbeddedChecker {
+    /// Spawns `command` through the shell. The command is expected to keep
+    /// running and read/write one check per line on stdin/stdout.
+    pub fn spawn(command: &str) -> io::Result<Self> {
+        let child = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        Ok(EmbeddedChecker { child })
+    }
+
+    pub fn check(&mut self, text: &str) -> io::Result<Vec<GrammarSuggestion>> {
+        let stdin = self
+            .child
+            .stdin
+            .as_mut()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::BrokenPipe, "checker stdin closed"))?;
+        writeln!(stdin, "{}", text.replace('\n', " "))?;
+        stdin.flush()?;
+
+        let stdout = self
+            .child
+            .stdout
+            .as_mut()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::BrokenPipe, "checker stdout closed"))?;
+        let mut reader = BufReader::new(stdout);
+        let mut line = String::new();
+        let mut suggestions = Vec::new();
+
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                break;
+            }
+
+            if let Some(suggestion) = parse_suggestion_line(&line) {
+                suggestions.push(suggestion);
+            }
+        }
+
+        Ok(suggestions)
+    }
+}
+
+impl Drop for EmbeddedChecker {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+    }
+}
+
+/// Parses one `original\tsuggestion\tmessage` response line, the protocol
+/// shared by [`EmbeddedChecker`] and [`HttpChecker`].
+fn parse_suggestion_line(line: &str) -> Option<GrammarSuggestion> {
+    let mut parts = line.trim_end_matches(['\r', '\n']).splitn(3, '\t');
+    let original = parts.next()?;
+    let suggestion = parts.next()?;
+    let message = parts.next()?;
+    Some(GrammarSuggestion {
+        original: original.to_string(),
+        suggestion: suggestion.to_string(),
+        message: message.to_string(),
+    })
+}
+
+/// Calls a `GRAMMAR_CHECK_ENDPOINT` server over plain HTTP/1.1 (no TLS, to
+/// avoid pulling in a TLS stack for what's meant to be a local or
+/// self-hosted checker). Posts the text as the request body and expects the
+/// same `original\tsuggestion\tmessage` line protocol back as
+/// [`EmbeddedChecker`], one suggestion per line.
+pub struct HttpChecker {
+    host: String,
+    port: u16,
+    path: String,
+}
+
+impl HttpChecker {
+    /// Parses `endpoint` as `http://host[:port][/path]`. Returns `Err` for
+    /// anything else (in particular `https://`, which isn't supported).
+    pub fn new(endpoint: &str) -> Result<Self, String> {
+        let rest = endpoint
+            .strip_prefix("http://")
+            .ok_or_else(|| format!("unsupported grammar check endpoint: {endpoint}"))?;
+
+        let (authority, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, "/"),
+        };
+
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (
+                host.to_string(),
+                port.parse::<u16>()
+                    .map_err(|_| format!("invalid port in endpoint: {endpoint}"))?,
+            ),
+            None => (authority.to_string(), 80),
+        };
+
+        if host.is_empty() {
+            return Err(format!("missing host in endpoint: {endpoint}"));
+        }
+
+        Ok(HttpChecker {
+            host,
+            port,
+            path: path.to_string(),
+        })
+    }
+
+    pub fn check(&self, text: &str) -> io::Result<Vec<GrammarSuggestion>> {
+        let body = text.replace('\n', " ");
+        let request = format!(
+            "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.path,
+            self.host,
+            body.len(),
+            body
+        );
+
+        let mut stream = TcpStream::connect((self.host.as_str(), self.port))?;
+        stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+        stream.write_all(request.as_bytes())?;
+        stream.flush()?;
+
+        let mut raw_response = String::new();
+        stream.read_to_string(&mut raw_response)?;
+
+        let body = raw_response
+            .split_once("\r\n\r\n")
+            .map(|(_, body)| body)
+            .unwrap_or(&raw_response);
+
+        Ok(body.lines().filter_map(parse_suggestion_line).collect())
+    }
+}
+
+/// Picks whichever checker `cfg` configures, preferring the HTTP endpoint
+/// when both are set. Returns `None` if `GRAMMAR_CHECK` is off or neither a
+/// command nor an endpoint is configured.
+pub enum GrammarChecker {
+    Embedded(EmbeddedChecker),
+    Http(HttpChecker),
+}
+
+impl GrammarChecker {
+    pub fn from_config(cfg: &EnvConfig) -> Option<Result<GrammarChecker, String>> {
+        if cfg.get("GRAMMAR_CHECK") != Some("true") {
+            return None;
+        }
+
+        let endpoint = cfg.get("GRAMMAR_CHECK_ENDPOINT").unwrap_or("");
+        if !endpoint.is_empty() {
+            return Some(HttpChecker::new(endpoint).map(GrammarChecker::Http));
+        }
+
+        let command = cfg.get("GRAMMAR_CHECK_COMMAND").unwrap_or("");
+        if !command.is_empty() {
+            return Some(
+                EmbeddedChecker::spawn(command)
+                    .map(GrammarChecker::Embedded)
+                    .map_err(|e| e.to_string()),
+            );
+        }
+
+        None
+    }
+
+    pub fn check(&mut self, text: &str) -> io::Result<Vec<GrammarSuggestion>> {
+        match self {
+            GrammarChecker::Embedded(checker) => checker.check(text),
+            GrammarChecker::Http(checker) => checker.check(text),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Fluent-based localization. UI strings live in `locales/<bundle>/main.ftl`
+//! (embedded at compile time via `include_str!`, since the repo has no asset
+//! pipeline to copy loose files next to the binary) and are looked up
+//! through the [`tr!`] macro instead of being hardcoded with `format!`.
+//!
+//! The active language is negotiated once, at startup, from `$LC_MESSAGES`/
+//! `$LANG`, falling back through any other configured locales to `en-US`.
+//! A lookup that isn't found in the negotiated locale falls through to the
+//! next one in the chain; if no locale has it, we log a warning and return
+//! the key itself rather than panicking, so a missing translation is visible
+//! but never fatal.
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use unic_langid::{langid, LanguageIdentifier};
+
+/// (locale id, embedded `.ftl` source). Add a new locale by dropping a
+/// `locales/<id>/main.ftl` file and listing it here.
+const RESOURCES: &[(&str, &str)] = &[
+    ("en-US", include_str!("../locales/en-US/main.ftl")),
+    ("de", include_str!("../locales/de/main.ftl")),
+];
+
+const FALLBACK_LOCALE: LanguageIdentifier = langid!("en-US");
+
+pub struct Localizer {
+    /// Negotiated locale chain, most preferred first, always ending in
+    /// [`FALLBACK_LOCALE`].
+    chain: Vec<LanguageIdentifier>,
+    bundles: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
+}
+
+impl Localizer {
+    fn new() -> Self {
+        let available: Vec<LanguageIdentifier> = RESOURCES
+            .iter()
+            .filter_map(|(id, _)| id.parse().ok())
+            .collect();
+
+        let requested = requested_locales();
+        let chain = negotiate(&requested, &available);
+
+        let mut bundles = HashMap::new();
+        for (id, source) in RESOURCES {
+            let Ok(locale): Result<LanguageIdentifier, _> = id.parse() else {
+                continue;
+            };
+            let resource = match FluentResource::try_new(source.to_string()) {
+                Ok(resource) => resource,
+                Err((resource, errors)) => {
+                    eprintln!("i18n: errors parsing {id}/main.ftl: {errors:?}");
+                    resource
+                }
+            };
+            let mut bundle = FluentBundle::new(vec![locale.clone()]);
+            if let Err(errors) = bundle.add_resource(resource) {
+                eprintln!("i18n: errors loading {id}/main.ftl: {errors:?}");
+            }
+            bundles.insert(locale, bundle);
+        }
+
+        Localizer { chain, bundles }
+    }
+
+    /// Looks up `key` in the negotiated locale chain and formats it with
+    /// `args`. Falls through to the next locale if a bundle is missing the
+    /// message, and returns `key` itself (after logging a warning) if none
+    /// of them have it.
+    pub fn format(&self, key: &str, args: Option<&FluentArgs>) -> String {
+        for locale in &self.chain {
+            let Some(bundle) = self.bundles.get(locale) else {
+                continue;
+            };
+            let Some(message) = bundle.get_message(key) else {
+                continue;
+            };
+            let Some(pattern) = message.value() else {
+                continue;
+            };
+
+            let mut errors = Vec::new();
+            let formatted = bundle.format_pattern(pattern, args, &mut errors);
+            if !errors.is_empty() {
+                eprintln!("i18n: errors formatting '{key}' in {locale}: {errors:?}");
+            }
+            return formatted.into_owned();
+        }
+
+        eprintln!("i18n: missing message '{key}' in every locale in the chain");
+        key.to_string()
+    }
+}
+
+/// Reads `$LC_MESSAGES` then `$LANG` (the usual gettext precedence), strips
+/// any `.UTF-8`/`@modifier` suffix, and parses what's left as a locale,
+/// ignoring the special "C"/"POSIX" values.
+fn requested_locales() -> Vec<LanguageIdentifier> {
+    ["LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .filter_map(|raw| {
+            let base = raw.split(['.', '@']).next().unwrap_or(&raw);
+            if base.is_empty() || base.eq_ignore_ascii_case("C") || base.eq_ignore_ascii_case("POSIX") {
+                return None;
+            }
+            base.replace('_', "-").parse().ok()
+        })
+        .collect()
+}
+
+/// Builds the fallback chain: each requested locale that we actually ship a
+/// bundle for (matching language first, then any region), followed by
+/// [`FALLBACK_LOCALE`] if it isn't already in the list.
+fn negotiate(
+    requested: &[LanguageIdentifier],
+    available: &[LanguageIdentifier],
+) -> Vec<LanguageIdentifier> {
+    let mut chain = Vec::new();
+
+    for want in requested {
+        if let Some(exact) = available.iter().find(|have| *have == want) {
+            if !chain.contains(exact) {
+                chain.push(exact.clone());
+            }
+        } else if let Some(same_language) = available
+            .iter()
+            .find(|have| have.language == want.language)
+        {
+            if !chain.contains(same_language) {
+                chain.push(same_language.clone());
+            }
+        }
+    }
+
+    if !chain.contains(&FALLBACK_LOCALE) {
+        chain.push(FALLBACK_LOCALE);
+    }
+
+    chain
+}
+
+static LOCALIZER: OnceLock<Localizer> = OnceLock::new();
+
+/// Negotiates the active locale chain and loads every bundled `.ftl`
+/// resource. Cheap enough to call unconditionally at startup; later calls
+/// are no-ops (the result is cached in [`LOCALIZER`]).
+pub fn init() {
+    LOCALIZER.get_or_init(Localizer::new);
+}
+
+/// The global [`Localizer`]. Panics if [`init`] hasn't run yet, same as any
+/// other "must be set up before use" global in this codebase.
+pub fn localizer() -> &'static Localizer {
+    LOCALIZER.get().expect("i18n::init() not called yet")
+}
+
+/// Converts a `tr!` argument into a [`FluentValue`]. Fluent distinguishes
+/// numbers (for plural selection) from plain strings, so integers get their
+/// own arm instead of going through `ToString`.
+pub trait IntoFluentValue {
+    fn into_fluent_value(self) -> FluentValue<'static>;
+}
+
+impl IntoFluentValue for i64 {
+    fn into_fluent_value(self) -> FluentValue<'static> {
+        FluentValue::from(self)
+    }
+}
+
+impl IntoFluentValue for usize {
+    fn into_fluent_value(self) -> FluentValue<'static> {
+        FluentValue::from(self as i64)
+    }
+}
+
+impl IntoFluentValue for &str {
+    fn into_fluent_value(self) -> FluentValue<'static> {
+        FluentValue::from(self.to_string())
+    }
+}
+
+impl IntoFluentValue for String {
+    fn into_fluent_value(self) -> FluentValue<'static> {
+        FluentValue::from(self)
+    }
+}
+
+/// Looks up a translated, formatted string by key: `tr!("no-logs-yet")` or,
+/// with interpolated arguments, `tr!("correction-count", count = total)`.
+#[macro_export]
+macro_rules! tr {
+    ($key:expr) => {
+        $crate::i18n::localizer().format($key, None)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        use $crate::i18n::IntoFluentValue;
+        let mut args = fluent_bundle::FluentArgs::new();
+        $( args.set(stringify!($name), $value.into_fluent_value()); )+
+        $crate::i18n::localizer().format($key, Some(&args))
+    }};
+}
@@ -0,0 +1,130 @@
+//! Parsing for the Debug Logs tab. The raw file written by the voice-input
+//! script is expected to look like `2026-07-30 10:15:32 [INFO] message`,
+//! one entry per line; [`parse_line`] turns each line into a [`LogEntry`]
+//! for the `gtk4::ColumnView` built in `main.rs`. Lines that don't match
+//! (a wrapped stack trace, say) are kept as an untimestamped `Info` entry
+//! rather than dropped, so nothing from the underlying file disappears.
+
+use chrono::{Local, NaiveDateTime, TimeZone};
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn label(self) -> &'static str {
+        match self {
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+
+    /// GTK style class used to color-tag the level column.
+    pub fn css_class(self) -> &'static str {
+        match self {
+            LogLevel::Info => "dim-label",
+            LogLevel::Warn => "warning",
+            LogLevel::Error => "error",
+        }
+    }
+
+    /// Index into the three-element "which levels are visible" arrays used
+    /// by the logs page's filter toggles.
+    pub fn index(self) -> usize {
+        match self {
+            LogLevel::Info => 0,
+            LogLevel::Warn => 1,
+            LogLevel::Error => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub timestamp: Option<chrono::DateTime<Local>>,
+    pub level: LogLevel,
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Whether `query` (already lowercased) appears anywhere a user would
+    /// expect full-text search to look: the message, or the raw level tag.
+    pub fn matches_search(&self, query_lower: &str) -> bool {
+        query_lower.is_empty()
+            || self.message.to_lowercase().contains(query_lower)
+            || self.level.label().to_lowercase().contains(query_lower)
+    }
+}
+
+/// Parses one raw log line into a [`LogEntry`]. Expects a leading
+/// `YYYY-MM-DD HH:MM:SS` timestamp and a `[LEVEL]` tag; each is optional on
+/// its own. Without a valid timestamp the entry is untimestamped, and
+/// without a recognized tag the level is `Info` and the tag-less remainder
+/// is kept as the message.
+pub fn parse_line(line: &str) -> LogEntry {
+    if let Some((timestamp, rest)) = split_timestamp(line) {
+        let (level, message) = split_level(rest);
+        return LogEntry {
+            timestamp: Some(timestamp),
+            level,
+            message: message.to_string(),
+        };
+    }
+
+    let (level, message) = split_level(line);
+    LogEntry {
+        timestamp: None,
+        level,
+        message: message.to_string(),
+    }
+}
+
+fn split_timestamp(line: &str) -> Option<(chrono::DateTime<Local>, &str)> {
+    let mut parts = line.splitn(3, ' ');
+    let date = parts.next()?;
+    let time = parts.next()?;
+    let rest = parts.next().unwrap_or("");
+
+    let naive = NaiveDateTime::parse_from_str(&format!("{date} {time}"), "%Y-%m-%d %H:%M:%S").ok()?;
+    let local = Local.from_local_datetime(&naive).single()?;
+    Some((local, rest))
+}
+
+fn split_level(rest: &str) -> (LogLevel, &str) {
+    for (tag, level) in [
+        ("[ERROR]", LogLevel::Error),
+        ("[WARN]", LogLevel::Warn),
+        ("[INFO]", LogLevel::Info),
+    ] {
+        if let Some(message) = rest.strip_prefix(tag) {
+            return (level, message.trim_start());
+        }
+    }
+    (LogLevel::Info, rest)
+}
+
+/// Formats the time since `start` as a short human string (`"3h 12m"`,
+/// `"45s"`), for the logs page's session-uptime header.
+pub fn format_uptime(start: SystemTime) -> String {
+    let elapsed = SystemTime::now()
+        .duration_since(start)
+        .unwrap_or_default()
+        .as_secs();
+
+    let hours = elapsed / 3600;
+    let minutes = (elapsed % 3600) / 60;
+    let seconds = elapsed % 60;
+
+    if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
@@ -1,23 +1,53 @@
+mod capture;
 mod config;
 mod database;
+mod editor_protocol;
+mod grammar;
+mod i18n;
+mod log_view;
+mod translate;
+mod waveform;
 
 use chrono::DateTime;
 use config::EnvConfig;
-use database::{Database, Recording};
+use database::{Database, GrammarSuggestion, MatchMode, Recording};
 use glib::clone;
+use gtk4::gio::prelude::*;
 use gtk4::prelude::*;
-use gtk4::{glib, Application, ApplicationWindow};
+use gtk4::{gio, glib, Application, ApplicationWindow};
 use libadwaita as adw;
 use libadwaita::prelude::*;
+use log_view::{LogEntry, LogLevel};
 use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::io::{Read, Seek, SeekFrom};
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::time::SystemTime;
 
+/// Default ALSA mixer control name for microphone input level, present on
+/// almost every capture device ("Mic" is the usual fallback on cards that
+/// don't expose a "Capture" control).
+const ALSA_CAPTURE_CONTROLS: [&str; 2] = ["Capture", "Mic"];
+
+/// How many samples the live input-level waveform keeps on screen. At the
+/// waveform tick's 100ms interval this is a 15 second rolling window.
+const MIC_WAVEFORM_HISTORY_LEN: usize = 150;
+
 const APP_ID: &str = "de.fluistern.gui";
 
 fn main() -> glib::ExitCode {
+    i18n::init();
+
+    // Editor plugins (Vim, VS Code, ...) drive dictation over stdio instead
+    // of launching the GTK history/settings window.
+    if std::env::args().any(|arg| arg == "--editor-protocol") {
+        let db = Arc::new(Database::new(get_db_file()).expect("Failed to open database"));
+        editor_protocol::run(db);
+        return glib::ExitCode::SUCCESS;
+    }
+
     let app = Application::builder().application_id(APP_ID).build();
 
     app.connect_activate(build_ui);
@@ -44,7 +74,7 @@ fn get_env_file() -> PathBuf {
     path
 }
 
-fn get_log_file() -> PathBuf {
+pub(crate) fn get_log_file() -> PathBuf {
     PathBuf::from("/tmp/voice-input-debug.log")
 }
 
@@ -89,9 +119,223 @@ struct AppState {
     config: Rc<RefCell<EnvConfig>>,
     history_box: gtk4::Box,
     corrections_box: gtk4::Box,
-    log_view: gtk4::TextView,
+    log_store: gio::ListStore,
+    log_filter: gtk4::CustomFilter,
+    log_search: Rc<RefCell<String>>,
+    log_visible_levels: Rc<RefCell<[bool; 3]>>,
+    log_read_offset: Rc<RefCell<u64>>,
+    log_uptime_label: gtk4::Label,
+    app_start: SystemTime,
     log_watcher_id: Rc<RefCell<Option<glib::SourceId>>>,
     last_log_mtime: Rc<RefCell<SystemTime>>,
+    mic_level_bar: gtk4::LevelBar,
+    mic_watcher_ids: Rc<RefCell<Vec<glib::SourceId>>>,
+    mic_mixer: Rc<RefCell<Option<alsa::mixer::Mixer>>>,
+    mic_waveform_area: gtk4::DrawingArea,
+    mic_waveform_readout: gtk4::Label,
+    mic_level_history: Rc<RefCell<VecDeque<f32>>>,
+    mic_waveform_tick_id: Rc<RefCell<Option<glib::SourceId>>>,
+    capture: Rc<RefCell<Option<capture::CapturePipeline>>>,
+    // Set for as long as `capture` holds a running pipeline; drained by
+    // `start_mic_waveform_tick` each tick so the Settings waveform reflects
+    // genuine per-buffer PCM amplitude instead of the ALSA mixer's capture
+    // level. `None` while idle, so the tick falls back to the mixer level.
+    mic_capture_level_rx: Rc<RefCell<Option<std::sync::mpsc::Receiver<f32>>>>,
+    // Keyed by audio_path, invalidated by mtime, so `refresh_history`
+    // rebuilding every row (on every refresh, correction save/delete, and
+    // grammar check) doesn't re-run `waveform::decode_peaks`'s synchronous
+    // full-file GStreamer decode for audio that hasn't changed.
+    peak_cache: Rc<RefCell<HashMap<String, (SystemTime, Rc<Vec<f32>>)>>>,
+}
+
+/// Lists available ALSA capture devices as `(device_id, display_name)`
+/// pairs, e.g. `("hw:0", "HDA Intel PCH")`. Falls back to a single
+/// `("default", "Default")` entry if enumeration fails so the combo row is
+/// never empty.
+fn list_capture_devices() -> Vec<(String, String)> {
+    let mut devices = Vec::new();
+
+    if let Ok(cards) = alsa::card::Iter::new().collect::<Result<Vec<_>, _>>() {
+        for card in cards {
+            let name = card
+                .get_name()
+                .unwrap_or_else(|_| format!("Card {}", card.get_index()));
+            devices.push((format!("hw:{}", card.get_index()), name));
+        }
+    }
+
+    if devices.is_empty() {
+        devices.push(("default".to_string(), "Default".to_string()));
+    }
+
+    devices
+}
+
+/// Finds `mixer`'s capture simple mixer element and returns its id alongside
+/// its capture volume range, trying each control name in
+/// [`ALSA_CAPTURE_CONTROLS`] until one exists.
+fn open_capture_selem(
+    mixer: &alsa::mixer::Mixer,
+) -> Option<(alsa::mixer::SelemId, i64, i64)> {
+    for name in ALSA_CAPTURE_CONTROLS {
+        let selem_id = alsa::mixer::SelemId::new(name, 0);
+        if let Some(selem) = mixer.find_selem(&selem_id) {
+            let (min, max) = selem.get_capture_volume_range();
+            return Some((selem_id, min, max));
+        }
+    }
+    None
+}
+
+/// Stops and removes any watchers registered by a previous call to
+/// [`start_mic_level_watcher`], so switching devices (or closing the
+/// window) never leaks glib sources.
+fn stop_mic_level_watcher(state: &Rc<AppState>) {
+    for id in state.mic_watcher_ids.borrow_mut().drain(..) {
+        id.remove();
+    }
+    *state.mic_mixer.borrow_mut() = None;
+    state.mic_level_bar.set_value(0.0);
+}
+
+/// Drives the Settings page's input-level meter with an event-driven
+/// watcher instead of a polling timer: opens `device`'s ALSA simple mixer
+/// element, registers its poll descriptors on the glib main context via
+/// `glib::unix_fd_add_local`, and on each wake reads the fresh capture
+/// level after handling pending mixer events.
+fn start_mic_level_watcher(state: &Rc<AppState>, device: &str) {
+    stop_mic_level_watcher(state);
+
+    let mixer = match alsa::mixer::Mixer::new(device, false) {
+        Ok(mixer) => mixer,
+        Err(_) => return,
+    };
+
+    let Some((selem_id, min, max)) = open_capture_selem(&mixer) else {
+        return;
+    };
+
+    let descriptors = match alsa::poll::Descriptors::get(&mixer) {
+        Ok(fds) => fds,
+        Err(_) => return,
+    };
+
+    *state.mic_mixer.borrow_mut() = Some(mixer);
+
+    let mut watcher_ids = Vec::new();
+    for pollfd in descriptors {
+        let state = state.clone();
+        let selem_id = selem_id.clone();
+        let id = glib::source::unix_fd_add_local(
+            pollfd.fd,
+            glib::IOCondition::IN,
+            move |_, _| {
+                if let Some(mixer) = state.mic_mixer.borrow().as_ref() {
+                    let _ = mixer.handle_events();
+                    if let Some(selem) = mixer.find_selem(&selem_id) {
+                        if let Ok(raw) =
+                            selem.get_capture_volume(alsa::mixer::SelemChannelId::FrontLeft)
+                        {
+                            let range = (max - min).max(1);
+                            let normalized = (raw - min) as f64 / range as f64;
+                            state.mic_level_bar.set_value(normalized.clamp(0.0, 1.0));
+                        }
+                    }
+                }
+
+                glib::ControlFlow::Continue
+            },
+        );
+        watcher_ids.push(id);
+    }
+
+    *state.mic_watcher_ids.borrow_mut() = watcher_ids;
+}
+
+/// Starts the tick that drives the Settings page's live waveform and
+/// RMS/peak readout, reusing the same polling-timer pattern as
+/// `start_log_watcher`. While `app.toggle-recording` has a capture
+/// pipeline running, `mic_capture_level_rx` carries genuine per-buffer PCM
+/// amplitude from `capture::CapturePipeline`'s appsink callback; each tick
+/// drains whatever arrived since the last one into the rolling history. When
+/// no capture pipeline is running (the common case — recording itself
+/// normally happens in the external tray/hotkey process), there's nothing to
+/// drain, so the tick falls back to sampling the same ALSA capture-level
+/// watcher that already feeds `mic_level_bar`.
+fn start_mic_waveform_tick(state: &Rc<AppState>) {
+    let id = glib::timeout_add_local(
+        std::time::Duration::from_millis(100),
+        clone!(@strong state => @default-return glib::ControlFlow::Break, move || {
+            let live_levels: Vec<f32> = match state.mic_capture_level_rx.borrow().as_ref() {
+                Some(rx) => rx.try_iter().collect(),
+                None => Vec::new(),
+            };
+
+            {
+                let mut history = state.mic_level_history.borrow_mut();
+                if live_levels.is_empty() {
+                    let level = state.mic_level_bar.value() as f32;
+                    if history.len() == MIC_WAVEFORM_HISTORY_LEN {
+                        history.pop_front();
+                    }
+                    history.push_back(level);
+                } else {
+                    for level in live_levels {
+                        if history.len() == MIC_WAVEFORM_HISTORY_LEN {
+                            history.pop_front();
+                        }
+                        history.push_back(level);
+                    }
+                }
+            }
+
+            let history = state.mic_level_history.borrow();
+            let peak = history.iter().cloned().fold(0.0f32, f32::max);
+            let rms = if history.is_empty() {
+                0.0
+            } else {
+                (history.iter().map(|v| v * v).sum::<f32>() / history.len() as f32).sqrt()
+            };
+            state.mic_waveform_readout.set_text(&tr!(
+                "rms-peak-readout",
+                rms = format!("{:.0}", rms * 100.0),
+                peak = format!("{:.0}", peak * 100.0)
+            ));
+
+            state.mic_waveform_area.queue_draw();
+
+            glib::ControlFlow::Continue
+        }),
+    );
+
+    *state.mic_waveform_tick_id.borrow_mut() = Some(id);
+}
+
+/// Finds the partial word immediately before the cursor in `buffer`
+/// (letters, digits and hyphens only), returning its start iterator and
+/// text, or `None` if the cursor isn't right after a word. Used by the
+/// correction editor's autocomplete popover in `create_recording_row`.
+fn partial_word_at_cursor(buffer: &gtk4::TextBuffer) -> Option<(gtk4::TextIter, String)> {
+    let cursor_iter = buffer.iter_at_mark(&buffer.get_insert());
+    let start_iter = buffer.start_iter();
+    let preceding = buffer.text(&start_iter, &cursor_iter, false);
+
+    let word: String = preceding
+        .chars()
+        .rev()
+        .take_while(|c| c.is_alphanumeric() || *c == '-')
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+
+    if word.is_empty() {
+        return None;
+    }
+
+    let mut word_start_iter = cursor_iter;
+    let _ = word_start_iter.backward_chars(word.chars().count() as i32);
+    Some((word_start_iter, word))
 }
 
 fn build_ui(app: &Application) {
@@ -119,7 +363,7 @@ fn build_ui(app: &Application) {
     // Refresh button
     let refresh_btn = gtk4::Button::builder()
         .icon_name("view-refresh-symbolic")
-        .tooltip_text("Refresh")
+        .tooltip_text(&tr!("refresh-tooltip"))
         .build();
     header.pack_start(&refresh_btn);
 
@@ -134,17 +378,17 @@ fn build_ui(app: &Application) {
         .vexpand(true)
         .child(&history_box)
         .build();
-    stack.add_titled(&history_scroll, Some("history"), "History");
+    stack.add_titled(&history_scroll, Some("history"), &tr!("tab-history"));
 
-    let (logs_page, log_view) = create_logs_page();
-    stack.add_titled(&logs_page, Some("logs"), "Debug Logs");
+    let (logs_page, log_widgets) = create_logs_page();
+    stack.add_titled(&logs_page, Some("logs"), &tr!("tab-logs"));
 
     let (settings_page, settings_widgets) = create_settings_page(config.clone());
-    stack.add_titled(&settings_page, Some("settings"), "Settings");
+    stack.add_titled(&settings_page, Some("settings"), &tr!("tab-settings"));
 
     let corrections_box = gtk4::Box::new(gtk4::Orientation::Vertical, 4);
-    let corrections_page = create_corrections_page(corrections_box.clone());
-    stack.add_titled(&corrections_page, Some("corrections"), "Corrections");
+    let (corrections_page, export_btn) = create_corrections_page(corrections_box.clone());
+    stack.add_titled(&corrections_page, Some("corrections"), &tr!("tab-corrections"));
 
     // View switcher
     let switcher = adw::ViewSwitcher::builder()
@@ -164,18 +408,183 @@ fn build_ui(app: &Application) {
         config: config.clone(),
         history_box: history_box.clone(),
         corrections_box: corrections_box.clone(),
-        log_view: log_view.clone(),
+        log_store: log_widgets.store.clone(),
+        log_filter: log_widgets.filter.clone(),
+        log_search: Rc::new(RefCell::new(String::new())),
+        log_visible_levels: Rc::new(RefCell::new([true, true, true])),
+        log_read_offset: Rc::new(RefCell::new(0)),
+        log_uptime_label: log_widgets.uptime_label.clone(),
+        app_start: SystemTime::now(),
         log_watcher_id: Rc::new(RefCell::new(None)),
         last_log_mtime: Rc::new(RefCell::new(SystemTime::UNIX_EPOCH)),
+        mic_level_bar: settings_widgets.mic_level_bar.clone(),
+        mic_watcher_ids: Rc::new(RefCell::new(Vec::new())),
+        mic_mixer: Rc::new(RefCell::new(None)),
+        mic_waveform_area: settings_widgets.mic_waveform_area.clone(),
+        mic_waveform_readout: settings_widgets.mic_waveform_readout.clone(),
+        mic_level_history: Rc::new(RefCell::new(VecDeque::with_capacity(MIC_WAVEFORM_HISTORY_LEN))),
+        mic_waveform_tick_id: Rc::new(RefCell::new(None)),
+        capture: Rc::new(RefCell::new(None)),
+        mic_capture_level_rx: Rc::new(RefCell::new(None)),
+        peak_cache: Rc::new(RefCell::new(HashMap::new())),
     });
 
     // Refresh history
     refresh_history(&state);
     refresh_corrections(&state);
 
+    // Filter the log view by level and full-text search
+    state.log_filter.set_filter_func(clone!(@strong state => move |obj| {
+        let Some(boxed) = obj.downcast_ref::<glib::BoxedAnyObject>() else {
+            return false;
+        };
+        let entry = boxed.borrow::<LogEntry>();
+        if !state.log_visible_levels.borrow()[entry.level.index()] {
+            return false;
+        }
+        entry.matches_search(&state.log_search.borrow().to_lowercase())
+    }));
+
+    log_widgets
+        .search_entry
+        .connect_search_changed(clone!(@strong state => move |entry| {
+            *state.log_search.borrow_mut() = entry.text().to_string();
+            state.log_filter.changed(gtk4::FilterChange::Different);
+        }));
+
+    for (toggle, level) in log_widgets
+        .level_toggles
+        .iter()
+        .zip([LogLevel::Info, LogLevel::Warn, LogLevel::Error])
+    {
+        toggle.connect_toggled(clone!(@strong state => move |toggle| {
+            state.log_visible_levels.borrow_mut()[level.index()] = toggle.is_active();
+            state.log_filter.changed(gtk4::FilterChange::Different);
+        }));
+    }
+
+    log_widgets.clear_btn.connect_clicked(clone!(@strong state => move |_| {
+        let log_file = get_log_file();
+        if log_file.exists() {
+            let _ = std::fs::remove_file(log_file);
+        }
+        state.log_store.remove_all();
+        *state.log_read_offset.borrow_mut() = 0;
+    }));
+
     // Start log watcher
     start_log_watcher(state.clone());
 
+    // Start the mic level meter for the currently selected device
+    if let Some((device, _)) = settings_widgets
+        .mic_devices
+        .get(settings_widgets.mic_combo.selected() as usize)
+    {
+        start_mic_level_watcher(&state, device);
+    }
+
+    state.mic_waveform_area.set_draw_func(clone!(@strong state => move |_, cr, width, height| {
+        let width = width as f64;
+        let height = height as f64;
+
+        let history = state.mic_level_history.borrow();
+        if history.is_empty() {
+            return;
+        }
+
+        let bar_width = width / MIC_WAVEFORM_HISTORY_LEN as f64;
+        for (i, &level) in history.iter().enumerate() {
+            let bar_height = (level as f64 * height).max(1.0);
+            let x = i as f64 * bar_width;
+
+            if level > 0.95 {
+                cr.set_source_rgb(0.85, 0.2, 0.2);
+            } else {
+                cr.set_source_rgb(0.3, 0.55, 0.95);
+            }
+            cr.rectangle(x, height - bar_height, (bar_width - 1.0).max(1.0), bar_height);
+            let _ = cr.fill();
+        }
+    }));
+
+    start_mic_waveform_tick(&state);
+
+    // Switch the level meter to the newly selected device
+    settings_widgets.mic_combo.connect_selected_notify(
+        clone!(@strong state, @strong settings_widgets => move |combo| {
+            if let Some((device, _)) = settings_widgets.mic_devices.get(combo.selected() as usize) {
+                start_mic_level_watcher(&state, device);
+            }
+        }),
+    );
+
+    // Switching the active profile immediately swaps in that profile's
+    // SYSTEM_PROMPT/LANGUAGE/MIC_SOURCE (see `config::PROFILE_KEYS`) rather
+    // than waiting for Save, so the other fields always reflect what's
+    // actually active.
+    settings_widgets.profile_combo.connect_selected_notify(
+        clone!(@strong config, @strong settings_widgets => move |combo| {
+            let index = combo.selected() as usize;
+            let name = if index == 0 {
+                String::new()
+            } else {
+                settings_widgets
+                    .profile_names
+                    .borrow()
+                    .get(index - 1)
+                    .cloned()
+                    .unwrap_or_default()
+            };
+
+            let mut cfg = config.borrow_mut();
+            cfg.set_active_profile(&name);
+            if let Err(e) = cfg.save() {
+                eprintln!("Failed to save settings: {}", e);
+            }
+            sync_profile_scoped_fields(&cfg, &settings_widgets);
+        }),
+    );
+
+    settings_widgets.add_profile_btn.connect_clicked(
+        clone!(@strong config, @strong settings_widgets => move |_| {
+            let name = settings_widgets.new_profile_entry.text().trim().to_string();
+            if name.is_empty() {
+                return;
+            }
+
+            let mut cfg = config.borrow_mut();
+            cfg.ensure_profile(&name);
+            cfg.set_active_profile(&name);
+            if let Err(e) = cfg.save() {
+                eprintln!("Failed to save settings: {}", e);
+            }
+            sync_profile_scoped_fields(&cfg, &settings_widgets);
+            drop(cfg);
+
+            settings_widgets.new_profile_entry.set_text("");
+
+            let mut profile_names = settings_widgets.profile_names.borrow_mut();
+            if !profile_names.contains(&name) {
+                profile_names.push(name.clone());
+                profile_names.sort();
+            }
+            if let Some(model) = settings_widgets
+                .profile_combo
+                .model()
+                .and_downcast::<gtk4::StringList>()
+            {
+                model.splice(0, model.n_items(), &[]);
+                model.append(&tr!("profile-default-option"));
+                for existing in profile_names.iter() {
+                    model.append(existing);
+                }
+            }
+            if let Some(index) = profile_names.iter().position(|n| *n == name) {
+                settings_widgets.profile_combo.set_selected((index + 1) as u32);
+            }
+        }),
+    );
+
     // Refresh button handler
     refresh_btn.connect_clicked(clone!(@strong state => move |_| {
         refresh_history(&state);
@@ -183,21 +592,136 @@ fn build_ui(app: &Application) {
         refresh_corrections(&state);
     }));
 
+    // App actions, so the whole app (history/settings/corrections, refresh,
+    // recording toggle) can be driven from the keyboard via the
+    // accelerators configured in the Settings shortcuts group below. Handy
+    // on tiling WMs (see `is_tiling_wm`), which already get a stripped
+    // header bar above.
+    let action_refresh = gio::SimpleAction::new("refresh", None);
+    action_refresh.connect_activate(clone!(@strong state => move |_, _| {
+        refresh_history(&state);
+        refresh_logs(&state);
+        refresh_corrections(&state);
+    }));
+    app.add_action(&action_refresh);
+
+    for (name, page) in [
+        ("view-history", "history"),
+        ("view-logs", "logs"),
+        ("view-settings", "settings"),
+        ("view-corrections", "corrections"),
+    ] {
+        let action = gio::SimpleAction::new(name, None);
+        action.connect_activate(clone!(@strong stack => move |_, _| {
+            stack.set_visible_child_name(page);
+        }));
+        app.add_action(&action);
+    }
+
+    let action_save_settings = gio::SimpleAction::new("save-settings", None);
+    action_save_settings.connect_activate(clone!(@strong settings_widgets => move |_, _| {
+        settings_widgets.save_btn.emit_clicked();
+    }));
+    app.add_action(&action_save_settings);
+
+    // Dispatch to Whisper and insertion into the active window still happen
+    // in the tray/hotkey process outside this GUI; this only starts/stops
+    // the in-process capture pipeline, which is enough to exercise it and
+    // to surface device errors in the Debug Logs page.
+    let action_toggle_recording = gio::SimpleAction::new("toggle-recording", None);
+    action_toggle_recording.connect_activate(clone!(@strong state => move |_, _| {
+        if state.capture.borrow().is_some() {
+            state.capture.borrow_mut().take();
+            state.mic_capture_level_rx.borrow_mut().take();
+            return;
+        }
+
+        let log_file = get_log_file();
+        let (level_tx, level_rx) = std::sync::mpsc::channel();
+        let result = capture::CapturePipeline::start(
+            capture::CaptureConfig::new(log_file.clone()),
+            move |segment| {
+                let duration_ms = segment.len() as f32 / 16.0;
+                let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+                let line = format!("{timestamp} [INFO] Captured {duration_ms:.0}ms speech segment\n");
+                if let Ok(mut file) = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&log_file)
+                {
+                    use std::io::Write;
+                    let _ = file.write_all(line.as_bytes());
+                }
+            },
+            move |level| {
+                let _ = level_tx.send(level);
+            },
+        );
+
+        match result {
+            Ok(pipeline) => {
+                *state.capture.borrow_mut() = Some(pipeline);
+                *state.mic_capture_level_rx.borrow_mut() = Some(level_rx);
+            }
+            Err(err) => eprintln!("failed to start capture pipeline: {err}"),
+        }
+    }));
+    app.add_action(&action_toggle_recording);
+
+    for (action, _label, _default_accel) in config::ACCEL_ACTIONS {
+        app.set_accels_for_action(action, &[config.borrow().accel_for(action)]);
+    }
+
+    // Shortcut capture: while `capturing_shortcut` holds a row index, the
+    // next keypress becomes that row's new accelerator instead of being
+    // dispatched normally.
+    let shortcut_capture = gtk4::EventControllerKey::new();
+    shortcut_capture.set_propagation_phase(gtk4::PropagationPhase::Capture);
+    shortcut_capture.connect_key_pressed(clone!(@strong settings_widgets => move |_, keyval, _, state_flags| {
+        let Some(index) = *settings_widgets.capturing_shortcut.borrow() else {
+            return glib::Propagation::Proceed;
+        };
+
+        let mod_mask = gtk4::accelerator_get_default_mod_mask();
+        let accel = gtk4::accelerator_name(keyval, state_flags & mod_mask);
+        if let Some(row) = settings_widgets.shortcut_rows.get(index) {
+            *row.current_accel.borrow_mut() = accel.to_string();
+            row.capture_btn.set_label(&accel);
+        }
+        *settings_widgets.capturing_shortcut.borrow_mut() = None;
+
+        glib::Propagation::Stop
+    }));
+    window.add_controller(shortcut_capture);
+
     // Connect settings save button
     settings_widgets
         .save_btn
-        .connect_clicked(clone!(@strong config, @strong settings_widgets => move |_| {
+        .connect_clicked(clone!(@strong config, @strong settings_widgets, @strong app => move |_| {
             let mut cfg = config.borrow_mut();
             cfg.set("GROQ_API_KEY".to_string(), settings_widgets.api_entry.text().to_string());
-            cfg.set("MIC_SOURCE".to_string(), settings_widgets.mic_entry.text().to_string());
+            if let Some((device, _)) = settings_widgets.mic_devices.get(settings_widgets.mic_combo.selected() as usize) {
+                cfg.set("MIC_SOURCE".to_string(), device.clone());
+            }
             cfg.set("LANGUAGE".to_string(), settings_widgets.lang_entry.text().to_string());
             cfg.set("NOTIFICATIONS".to_string(), if settings_widgets.notif_switch.is_active() { "true" } else { "false" }.to_string());
             cfg.set("TRAY_ICON".to_string(), if settings_widgets.tray_switch.is_active() { "true" } else { "false" }.to_string());
+            cfg.set("TRANSLATE_TO".to_string(), settings_widgets.translate_to_entry.text().to_string());
+            cfg.set("TRANSLATE_COMMAND".to_string(), settings_widgets.translate_command_entry.text().to_string());
+            cfg.set("GRAMMAR_CHECK".to_string(), if settings_widgets.grammar_check_switch.is_active() { "true" } else { "false" }.to_string());
+            cfg.set("GRAMMAR_CHECK_ENDPOINT".to_string(), settings_widgets.grammar_endpoint_entry.text().to_string());
+            cfg.set("GRAMMAR_CHECK_COMMAND".to_string(), settings_widgets.grammar_command_entry.text().to_string());
 
             let buffer = settings_widgets.prompt_view.buffer();
             let prompt = buffer.text(&buffer.start_iter(), &buffer.end_iter(), false);
             cfg.set("SYSTEM_PROMPT".to_string(), prompt.to_string());
 
+            for row in &settings_widgets.shortcut_rows {
+                let accel = row.current_accel.borrow().clone();
+                cfg.set_accel(&row.action, &accel);
+                app.set_accels_for_action(&row.action, &[accel.as_str()]);
+            }
+
             if let Err(e) = cfg.save() {
                 eprintln!("Failed to save settings: {}", e);
             } else {
@@ -211,21 +735,56 @@ fn build_ui(app: &Application) {
             settings_widgets.prompt_view.buffer().set_text(EnvConfig::get_default_system_prompt());
         }));
 
-    settings_widgets
-        .clear_logs_btn
-        .connect_clicked(clone!(@strong state => move |_| {
-            let log_file = get_log_file();
-            if log_file.exists() {
-                let _ = std::fs::remove_file(log_file);
+    // Export corrections as a few-shot prompt block (plain text or JSONL,
+    // picked by the extension of the chosen filename).
+    export_btn.connect_clicked(clone!(@strong state, @strong window => move |_| {
+        let corrections = state.db.get_corrections().unwrap_or_default();
+
+        let txt_filter = gtk4::FileFilter::new();
+        txt_filter.set_name(Some(&tr!("export-filter-plain-text")));
+        txt_filter.add_pattern("*.txt");
+
+        let jsonl_filter = gtk4::FileFilter::new();
+        jsonl_filter.set_name(Some(&tr!("export-filter-jsonl")));
+        jsonl_filter.add_pattern("*.jsonl");
+
+        let filters = gtk4::gio::ListStore::new::<gtk4::FileFilter>();
+        filters.append(&txt_filter);
+        filters.append(&jsonl_filter);
+
+        let dialog = gtk4::FileDialog::builder()
+            .title(&tr!("export-corrections-button"))
+            .initial_name("corrections.txt")
+            .filters(&filters)
+            .build();
+
+        dialog.save(Some(&window), None::<&gtk4::gio::Cancellable>, clone!(@strong corrections => move |result| {
+            if let Ok(file) = result {
+                if let Some(path) = file.path() {
+                    let is_jsonl = path.extension().and_then(|e| e.to_str()) == Some("jsonl");
+                    let content = if is_jsonl {
+                        database::corrections_to_few_shot_jsonl(&corrections)
+                    } else {
+                        database::corrections_to_few_shot_text(&corrections)
+                    };
+                    if let Err(e) = std::fs::write(&path, content) {
+                        eprintln!("Failed to export corrections: {}", e);
+                    }
+                }
             }
-            state.log_view.buffer().set_text("");
         }));
+    }));
 
     // Cleanup on window close
     window.connect_close_request(clone!(@strong state => move |_| {
         if let Some(id) = state.log_watcher_id.borrow_mut().take() {
             id.remove();
         }
+        stop_mic_level_watcher(&state);
+        if let Some(id) = state.mic_waveform_tick_id.borrow_mut().take() {
+            id.remove();
+        }
+        state.capture.borrow_mut().take();
         glib::Propagation::Proceed
     }));
 
@@ -280,7 +839,7 @@ fn refresh_history(state: &Rc<AppState>) {
 
     if recordings.is_empty() {
         let empty_label = gtk4::Label::builder()
-            .label("No recordings yet.\nStart a recording with the Voice Input toggle.")
+            .label(&tr!("no-recordings-yet"))
             .margin_top(50)
             .css_classes(vec!["dim-label"])
             .build();
@@ -293,6 +852,31 @@ fn refresh_history(state: &Rc<AppState>) {
     }
 }
 
+/// Returns `audio_path`'s decoded waveform peaks, decoding and caching them
+/// in `state.peak_cache` on a miss and re-decoding if the file's mtime has
+/// moved on since the cached entry (e.g. a re-recorded/overwritten file at
+/// the same path).
+fn cached_peaks(state: &Rc<AppState>, audio_path: &str) -> Rc<Vec<f32>> {
+    let mtime = std::fs::metadata(audio_path)
+        .and_then(|meta| meta.modified())
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+    if let Some((cached_mtime, peaks)) = state.peak_cache.borrow().get(audio_path) {
+        if *cached_mtime == mtime {
+            return peaks.clone();
+        }
+    }
+
+    let peaks = Rc::new(
+        waveform::decode_peaks(std::path::Path::new(audio_path), 40).unwrap_or_default(),
+    );
+    state
+        .peak_cache
+        .borrow_mut()
+        .insert(audio_path.to_string(), (mtime, peaks.clone()));
+    peaks
+}
+
 fn create_recording_row(recording: Recording, db: Arc<Database>, state: Rc<AppState>) -> gtk4::Box {
     let main_box = gtk4::Box::new(gtk4::Orientation::Vertical, 0);
     main_box.add_css_class("card");
@@ -352,10 +936,110 @@ fn create_recording_row(recording: Recording, db: Arc<Database>, state: Rc<AppSt
         .build();
     header_content.append(&preview_label);
 
+    // Playback + waveform (only when the recording has a stored audio file)
+    let audio_path = recording
+        .audio_path
+        .as_ref()
+        .filter(|p| std::path::Path::new(p).exists())
+        .cloned();
+
+    if let Some(audio_path) = audio_path {
+        let play_btn = gtk4::Button::builder()
+            .icon_name("media-playback-start-symbolic")
+            .valign(gtk4::Align::Center)
+            .build();
+        play_btn.add_css_class("flat");
+
+        let waveform_area = gtk4::DrawingArea::builder()
+            .content_width(80)
+            .content_height(28)
+            .valign(gtk4::Align::Center)
+            .build();
+
+        let peaks = cached_peaks(&state, &audio_path);
+        let player: Rc<RefCell<Option<waveform::Player>>> = Rc::new(RefCell::new(None));
+        let cursor = Rc::new(RefCell::new(0.0f64));
+
+        waveform_area.set_draw_func(clone!(@strong peaks, @strong cursor => move |_, cr, width, height| {
+            let bar_count = peaks.len().max(1);
+            let bar_width = width as f64 / bar_count as f64;
+            let played = *cursor.borrow();
+
+            for (i, peak) in peaks.iter().enumerate() {
+                let bar_height = (*peak as f64).max(0.03) * height as f64;
+                let x = i as f64 * bar_width;
+                let y = (height as f64 - bar_height) / 2.0;
+
+                if i as f64 / bar_count as f64 <= played {
+                    cr.set_source_rgb(0.208, 0.518, 0.894);
+                } else {
+                    cr.set_source_rgb(0.6, 0.6, 0.6);
+                }
+                cr.rectangle(x, y, (bar_width - 1.0).max(1.0), bar_height);
+                let _ = cr.fill();
+            }
+        }));
+
+        play_btn.connect_clicked(clone!(@strong player, @strong waveform_area, @strong cursor, @strong play_btn, @strong audio_path => move |_| {
+            let is_playing = player
+                .borrow()
+                .as_ref()
+                .map(|p| p.state() == waveform::PlaybackState::Playing)
+                .unwrap_or(false);
+
+            if is_playing {
+                if let Some(p) = player.borrow().as_ref() {
+                    p.pause();
+                }
+                play_btn.set_icon_name("media-playback-start-symbolic");
+                return;
+            }
+
+            if player.borrow().is_none() {
+                *player.borrow_mut() = waveform::Player::new(std::path::Path::new(&audio_path));
+            }
+
+            let duration_ms = player.borrow().as_ref().and_then(|p| p.duration_ms());
+            if let Some(p) = player.borrow().as_ref() {
+                p.play();
+            }
+            play_btn.set_icon_name("media-playback-pause-symbolic");
+
+            glib::timeout_add_local(std::time::Duration::from_millis(100), clone!(@weak waveform_area, @weak play_btn, @weak player, @weak cursor => @default-return glib::ControlFlow::Break, move || {
+                let player_ref = player.borrow();
+                let Some(p) = player_ref.as_ref() else {
+                    return glib::ControlFlow::Break;
+                };
+
+                if p.is_eos() {
+                    play_btn.set_icon_name("media-playback-start-symbolic");
+                    *cursor.borrow_mut() = 0.0;
+                    waveform_area.queue_draw();
+                    return glib::ControlFlow::Break;
+                }
+
+                if p.state() != waveform::PlaybackState::Playing {
+                    return glib::ControlFlow::Break;
+                }
+
+                if let (Some(pos), Some(dur)) = (p.position_ms(), duration_ms) {
+                    if dur > 0 {
+                        *cursor.borrow_mut() = (pos as f64 / dur as f64).clamp(0.0, 1.0);
+                    }
+                }
+                waveform_area.queue_draw();
+                glib::ControlFlow::Continue
+            }));
+        }));
+
+        header_content.append(&play_btn);
+        header_content.append(&waveform_area);
+    }
+
     // Status
     let status_label = if recording.user_correction.is_some() {
         gtk4::Label::builder()
-            .label("corrected")
+            .label(&tr!("recording-status-corrected"))
             .css_classes(vec!["success", "caption"])
             .build()
     } else if recording.success {
@@ -365,7 +1049,7 @@ fn create_recording_row(recording: Recording, db: Arc<Database>, state: Rc<AppSt
             .build()
     } else {
         gtk4::Label::builder()
-            .label("Error")
+            .label(&tr!("recording-status-error"))
             .css_classes(vec!["error", "caption"])
             .build()
     };
@@ -399,7 +1083,7 @@ fn create_recording_row(recording: Recording, db: Arc<Database>, state: Rc<AppSt
     // Whisper output section
     let whisper_group = gtk4::Box::new(gtk4::Orientation::Vertical, 4);
     let whisper_header = gtk4::Label::builder()
-        .label("Whisper (Raw Transcription)")
+        .label(&tr!("whisper-section-heading"))
         .css_classes(vec!["heading"])
         .xalign(0.0)
         .build();
@@ -432,7 +1116,7 @@ fn create_recording_row(recording: Recording, db: Arc<Database>, state: Rc<AppSt
     // LLM output section
     let llm_group = gtk4::Box::new(gtk4::Orientation::Vertical, 4);
     let llm_header = gtk4::Label::builder()
-        .label("LLM (Formatted)")
+        .label(&tr!("llm-section-heading"))
         .css_classes(vec!["heading"])
         .xalign(0.0)
         .build();
@@ -462,10 +1146,44 @@ fn create_recording_row(recording: Recording, db: Arc<Database>, state: Rc<AppSt
     llm_group.append(&llm_frame);
     detail_box.append(&llm_group);
 
+    // Translation section, populated by the "Translate" button below via
+    // `translate::Translator` and persisted with `Database::update_translation`.
+    let translation_group = gtk4::Box::new(gtk4::Orientation::Vertical, 4);
+    let translation_header = gtk4::Label::builder()
+        .label(&tr!("translation-section-heading"))
+        .css_classes(vec!["heading"])
+        .xalign(0.0)
+        .build();
+    translation_group.append(&translation_header);
+
+    let translation_frame = gtk4::Frame::new(None);
+    let translation_scroll = gtk4::ScrolledWindow::builder()
+        .min_content_height(60)
+        .max_content_height(120)
+        .build();
+
+    let translation_view = gtk4::TextView::builder()
+        .editable(false)
+        .wrap_mode(gtk4::WrapMode::Word)
+        .cursor_visible(false)
+        .margin_top(8)
+        .margin_bottom(8)
+        .margin_start(8)
+        .margin_end(8)
+        .build();
+    translation_view
+        .buffer()
+        .set_text(recording.translated_output.as_deref().unwrap_or(""));
+
+    translation_scroll.set_child(Some(&translation_view));
+    translation_frame.set_child(Some(&translation_scroll));
+    translation_group.append(&translation_frame);
+    detail_box.append(&translation_group);
+
     // Correction section
     let corr_group = gtk4::Box::new(gtk4::Orientation::Vertical, 4);
     let corr_header = gtk4::Label::builder()
-        .label("Your Correction (what you actually meant)")
+        .label(&tr!("correction-section-heading"))
         .css_classes(vec!["heading", "accent"])
         .xalign(0.0)
         .build();
@@ -499,19 +1217,113 @@ fn create_recording_row(recording: Recording, db: Arc<Database>, state: Rc<AppSt
     corr_frame.set_child(Some(&corr_scroll));
     corr_group.append(&corr_frame);
 
+    // Autocomplete popover: suggests recurring corrected words as the user
+    // types, so names/jargon stay spelled consistently across recordings.
+    let vocabulary = Rc::new(db.correction_vocabulary().unwrap_or_default());
+    let corr_popover = gtk4::Popover::builder()
+        .autohide(false)
+        .has_arrow(true)
+        .build();
+    let corr_listbox = gtk4::ListBox::new();
+    corr_popover.set_child(Some(&corr_listbox));
+    corr_popover.set_parent(&corr_view);
+
+    corr_view.buffer().connect_changed(clone!(@strong vocabulary, @strong corr_popover, @strong corr_listbox, @strong corr_view => move |buffer| {
+        let Some((_, partial)) = partial_word_at_cursor(buffer) else {
+            corr_popover.popdown();
+            return;
+        };
+
+        if partial.chars().count() < 2 {
+            corr_popover.popdown();
+            return;
+        }
+
+        let partial_lower = partial.to_lowercase();
+        let matches: Vec<&String> = vocabulary
+            .iter()
+            .filter(|word| word.to_lowercase().starts_with(&partial_lower) && word.to_lowercase() != partial_lower)
+            .take(5)
+            .collect();
+
+        while let Some(child) = corr_listbox.first_child() {
+            corr_listbox.remove(&child);
+        }
+
+        if matches.is_empty() {
+            corr_popover.popdown();
+            return;
+        }
+
+        for word in &matches {
+            let row_label = gtk4::Label::builder()
+                .label(word.as_str())
+                .xalign(0.0)
+                .margin_top(4)
+                .margin_bottom(4)
+                .margin_start(8)
+                .margin_end(8)
+                .build();
+            corr_listbox.append(&row_label);
+        }
+        if let Some(first_row) = corr_listbox.row_at_index(0) {
+            corr_listbox.select_row(Some(&first_row));
+        }
+
+        let (cursor_rect, _) = corr_view.cursor_locations(None::<&gtk4::TextIter>);
+        corr_popover.set_pointing_to(Some(&cursor_rect));
+        corr_popover.popup();
+    }));
+
+    let corr_key_controller = gtk4::EventControllerKey::new();
+    corr_key_controller.connect_key_pressed(clone!(@strong corr_popover, @strong corr_listbox, @strong corr_view => move |_, keyval, _, _| {
+        if !corr_popover.is_visible() {
+            return glib::Propagation::Proceed;
+        }
+
+        if keyval == gtk4::gdk::Key::Tab || keyval == gtk4::gdk::Key::Return || keyval == gtk4::gdk::Key::KP_Enter {
+            if let Some(row) = corr_listbox.selected_row() {
+                if let Some(child) = row.child() {
+                    if let Ok(label) = child.downcast::<gtk4::Label>() {
+                        let suggestion = label.text().to_string();
+                        let buffer = corr_view.buffer();
+                        if let Some((mut word_start, _)) = partial_word_at_cursor(&buffer) {
+                            let mut cursor_iter = buffer.iter_at_mark(&buffer.get_insert());
+                            buffer.delete(&mut word_start, &mut cursor_iter);
+                            buffer.insert(&mut word_start, &suggestion);
+                        }
+                    }
+                }
+            }
+            corr_popover.popdown();
+            return glib::Propagation::Stop;
+        }
+
+        if keyval == gtk4::gdk::Key::Escape {
+            corr_popover.popdown();
+            return glib::Propagation::Stop;
+        }
+
+        glib::Propagation::Proceed
+    }));
+    corr_view.add_controller(corr_key_controller);
+
     // Button row
     let btn_row = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
 
     let save_btn = gtk4::Button::builder()
-        .label("Save Correction")
+        .label(&tr!("save-correction-button"))
         .css_classes(vec!["suggested-action"])
         .build();
 
     let delete_btn = gtk4::Button::builder()
-        .label("Delete")
+        .label(&tr!("delete-button"))
         .css_classes(vec!["destructive-action"])
         .build();
 
+    let grammar_btn = gtk4::Button::builder().label(&tr!("check-grammar-button")).build();
+    let translate_btn = gtk4::Button::builder().label(&tr!("translate-button")).build();
+
     let rec_id = recording.id;
     save_btn.connect_clicked(clone!(@strong db, @strong corr_view, @strong state => move |_| {
         let buffer = corr_view.buffer();
@@ -539,7 +1351,101 @@ fn create_recording_row(recording: Recording, db: Arc<Database>, state: Rc<AppSt
 
     btn_row.append(&save_btn);
     btn_row.append(&delete_btn);
+    btn_row.append(&grammar_btn);
+    btn_row.append(&translate_btn);
     corr_group.append(&btn_row);
+
+    translate_btn.connect_clicked(clone!(@strong db, @strong state, @strong corr_view, @strong translation_view => move |_| {
+        let translator = match translate::from_config(&state.config.borrow()) {
+            Some(Ok(translator)) => translator,
+            Some(Err(e)) => {
+                eprintln!("Failed to start translator: {}", e);
+                return;
+            }
+            None => return,
+        };
+        let mut translator = translator;
+
+        let buffer = corr_view.buffer();
+        let text = buffer.text(&buffer.start_iter(), &buffer.end_iter(), false);
+
+        match translator.translate(&text) {
+            Ok(translated) => {
+                if let Err(e) = db.update_translation(rec_id, &translated) {
+                    eprintln!("Failed to save translation: {}", e);
+                }
+                translation_view.buffer().set_text(&translated);
+            }
+            Err(e) => eprintln!("Translation failed: {}", e),
+        }
+    }));
+
+    // Grammar suggestions ("did you mean" fixes), populated from whatever
+    // the last check stored and refreshed in place by `grammar_btn` and by
+    // accepting individual suggestions.
+    let grammar_box = gtk4::Box::new(gtk4::Orientation::Vertical, 4);
+    corr_group.append(&grammar_box);
+    let grammar_suggestions = Rc::new(RefCell::new(recording.grammar_suggestions.clone()));
+    render_grammar_suggestions(&grammar_box, &grammar_suggestions, rec_id, &db, &state);
+
+    // `HttpChecker::check` blocks on a TCP round trip with up to a 10s read
+    // timeout; run it (and `EmbeddedChecker::check`'s stdio round trip) on a
+    // background thread and poll for the result, same shape as the other
+    // polling timers in this file, instead of freezing the GTK main loop.
+    grammar_btn.connect_clicked(clone!(@strong db, @strong state, @strong corr_view, @strong grammar_box, @strong grammar_suggestions, @strong grammar_btn => move |_| {
+        let mut checker = match grammar::GrammarChecker::from_config(&state.config.borrow()) {
+            Some(Ok(checker)) => checker,
+            Some(Err(e)) => {
+                eprintln!("Failed to start grammar checker: {}", e);
+                return;
+            }
+            None => return,
+        };
+
+        let buffer = corr_view.buffer();
+        let text = buffer.text(&buffer.start_iter(), &buffer.end_iter(), false).to_string();
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(checker.check(&text));
+        });
+
+        let original_label = grammar_btn.label().map(|l| l.to_string()).unwrap_or_default();
+        grammar_btn.set_sensitive(false);
+        grammar_btn.set_label(&tr!("checking-grammar-button"));
+
+        glib::timeout_add_local(std::time::Duration::from_millis(100), clone!(
+            @strong db, @strong state, @strong grammar_box, @strong grammar_suggestions, @strong grammar_btn, @strong original_label
+            => @default-return glib::ControlFlow::Break, move || {
+                let result = match rx.try_recv() {
+                    Ok(result) => result,
+                    Err(std::sync::mpsc::TryRecvError::Empty) => return glib::ControlFlow::Continue,
+                    Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                        eprintln!("Grammar check thread vanished without a result");
+                        grammar_btn.set_sensitive(true);
+                        grammar_btn.set_label(&original_label);
+                        return glib::ControlFlow::Break;
+                    }
+                };
+
+                match result {
+                    Ok(suggestions) => {
+                        if let Err(e) = db.update_grammar_suggestions(rec_id, &suggestions) {
+                            eprintln!("Failed to save grammar suggestions: {}", e);
+                        }
+                        *grammar_suggestions.borrow_mut() = suggestions;
+                        render_grammar_suggestions(&grammar_box, &grammar_suggestions, rec_id, &db, &state);
+                    }
+                    Err(e) => eprintln!("Grammar check failed: {}", e),
+                }
+
+                grammar_btn.set_sensitive(true);
+                grammar_btn.set_label(&original_label);
+                glib::ControlFlow::Break
+            }
+        ));
+    }));
+
     detail_box.append(&corr_group);
 
     // Error message
@@ -566,48 +1472,284 @@ fn create_recording_row(recording: Recording, db: Arc<Database>, state: Rc<AppSt
     main_box
 }
 
+/// Rebuilds `grammar_box`'s children from `suggestions`, one row per "did
+/// you mean" fix with an Accept button that feeds it into `corrections`
+/// (via `Database::accept_grammar_suggestion`) and drops it from the list.
+fn render_grammar_suggestions(
+    grammar_box: &gtk4::Box,
+    suggestions: &Rc<RefCell<Vec<GrammarSuggestion>>>,
+    recording_id: i64,
+    db: &Arc<Database>,
+    state: &Rc<AppState>,
+) {
+    while let Some(child) = grammar_box.first_child() {
+        grammar_box.remove(&child);
+    }
+
+    for (index, suggestion) in suggestions.borrow().iter().enumerate() {
+        let row = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
+
+        let text = gtk4::Label::builder()
+            .label(&format!(
+                "\"{}\" -> \"{}\" ({})",
+                suggestion.original, suggestion.suggestion, suggestion.message
+            ))
+            .css_classes(vec!["caption"])
+            .xalign(0.0)
+            .hexpand(true)
+            .wrap(true)
+            .build();
+        row.append(&text);
+
+        let accept_btn = gtk4::Button::builder().label(&tr!("accept-button")).build();
+        accept_btn.connect_clicked(clone!(@strong db, @strong state, @strong suggestions, @strong grammar_box => move |_| {
+            let accepted = suggestions.borrow()[index].clone();
+            if let Err(e) = db.accept_grammar_suggestion(&accepted) {
+                eprintln!("Failed to accept grammar suggestion: {}", e);
+                return;
+            }
+
+            suggestions.borrow_mut().remove(index);
+            if let Err(e) = db.update_grammar_suggestions(recording_id, &suggestions.borrow()) {
+                eprintln!("Failed to save grammar suggestions: {}", e);
+            }
+            render_grammar_suggestions(&grammar_box, &suggestions, recording_id, &db, &state);
+            refresh_corrections(&state);
+        }));
+        row.append(&accept_btn);
+
+        grammar_box.append(&row);
+    }
+}
+
+/// One row in the Settings "Keyboard Shortcuts" group: the action it binds,
+/// the row showing its current accelerator, and the button that starts
+/// capturing a replacement (see the shared `capturing_shortcut` state).
+#[derive(Clone)]
+struct ShortcutRow {
+    action: String,
+    row: adw::ActionRow,
+    capture_btn: gtk4::Button,
+    current_accel: Rc<RefCell<String>>,
+}
+
 #[derive(Clone)]
 struct SettingsWidgets {
     api_entry: adw::EntryRow,
-    mic_entry: adw::EntryRow,
+    profile_combo: adw::ComboRow,
+    profile_names: Rc<RefCell<Vec<String>>>,
+    new_profile_entry: adw::EntryRow,
+    add_profile_btn: gtk4::Button,
+    mic_combo: adw::ComboRow,
+    mic_devices: Vec<(String, String)>,
+    mic_level_bar: gtk4::LevelBar,
+    mic_waveform_area: gtk4::DrawingArea,
+    mic_waveform_readout: gtk4::Label,
     lang_entry: adw::EntryRow,
     notif_switch: adw::SwitchRow,
     tray_switch: adw::SwitchRow,
+    translate_to_entry: adw::EntryRow,
+    translate_command_entry: adw::EntryRow,
+    grammar_check_switch: adw::SwitchRow,
+    grammar_endpoint_entry: adw::EntryRow,
+    grammar_command_entry: adw::EntryRow,
     prompt_view: gtk4::TextView,
     reset_prompt_btn: gtk4::Button,
+    shortcut_rows: Vec<ShortcutRow>,
+    reset_shortcuts_btn: gtk4::Button,
+    capturing_shortcut: Rc<RefCell<Option<usize>>>,
     save_btn: gtk4::Button,
-    clear_logs_btn: gtk4::Button,
 }
 
-fn create_logs_page() -> (gtk4::Box, gtk4::TextView) {
+/// Widgets for the Debug Logs page: a `gtk4::ColumnView` over a
+/// `gio::ListStore` of [`LogEntry`] (boxed via `glib::BoxedAnyObject`, since
+/// this repo has no GObject subclasses of its own), filtered by `filter`
+/// through the level toggles and search entry below.
+#[derive(Clone)]
+struct LogViewWidgets {
+    store: gio::ListStore,
+    filter: gtk4::CustomFilter,
+    search_entry: gtk4::SearchEntry,
+    level_toggles: [gtk4::ToggleButton; 3],
+    clear_btn: gtk4::Button,
+    uptime_label: gtk4::Label,
+}
+
+/// Builds one `gtk4::ColumnViewColumn` backed by a `BoxedAnyObject<LogEntry>`
+/// model: `text_fn` renders the cell text, `css_class_fn` (if given) tags it
+/// with a style class (used for the color-coded Level column).
+fn log_column(
+    title: &str,
+    expand: bool,
+    text_fn: impl Fn(&LogEntry) -> String + 'static,
+    css_class_fn: Option<fn(&LogEntry) -> &'static str>,
+) -> gtk4::ColumnViewColumn {
+    let factory = gtk4::SignalListItemFactory::new();
+
+    factory.connect_setup(|_, item| {
+        let item = item.downcast_ref::<gtk4::ListItem>().unwrap();
+        let label = gtk4::Label::builder().xalign(0.0).build();
+        item.set_child(Some(&label));
+    });
+
+    factory.connect_bind(move |_, item| {
+        let item = item.downcast_ref::<gtk4::ListItem>().unwrap();
+        let Some(boxed) = item
+            .item()
+            .and_then(|obj| obj.downcast::<glib::BoxedAnyObject>().ok())
+        else {
+            return;
+        };
+        let entry = boxed.borrow::<LogEntry>();
+        let Some(label) = item.child().and_then(|w| w.downcast::<gtk4::Label>().ok()) else {
+            return;
+        };
+
+        label.set_text(&text_fn(&entry));
+        if let Some(css_class_fn) = css_class_fn {
+            label.set_css_classes(&[css_class_fn(&entry)]);
+        }
+    });
+
+    gtk4::ColumnViewColumn::builder()
+        .title(title)
+        .factory(&factory)
+        .expand(expand)
+        .build()
+}
+
+fn create_logs_page() -> (gtk4::Box, LogViewWidgets) {
     let vbox = gtk4::Box::new(gtk4::Orientation::Vertical, 8);
     vbox.set_margin_top(12);
     vbox.set_margin_bottom(12);
     vbox.set_margin_start(12);
     vbox.set_margin_end(12);
 
+    let header = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
+
     let info = gtk4::Label::builder()
-        .label("Debug-Logs vom Voice Input Script")
+        .label(&tr!("logs-info"))
         .css_classes(vec!["dim-label"])
         .xalign(0.0)
+        .hexpand(true)
         .build();
-    vbox.append(&info);
+    header.append(&info);
+
+    let uptime_label = gtk4::Label::builder()
+        .label(&tr!("uptime-label", value = "0s"))
+        .css_classes(vec!["dim-label"])
+        .build();
+    header.append(&uptime_label);
+    vbox.append(&header);
+
+    let toolbar = gtk4::Box::new(gtk4::Orientation::Horizontal, 6);
+
+    let level_toggles = [
+        gtk4::ToggleButton::builder()
+            .label(LogLevel::Info.label())
+            .active(true)
+            .build(),
+        gtk4::ToggleButton::builder()
+            .label(LogLevel::Warn.label())
+            .active(true)
+            .build(),
+        gtk4::ToggleButton::builder()
+            .label(LogLevel::Error.label())
+            .active(true)
+            .build(),
+    ];
+    for toggle in &level_toggles {
+        toolbar.append(toggle);
+    }
 
-    let clear_btn = gtk4::Button::builder().label("Clear Logs").build();
-    vbox.append(&clear_btn);
+    let search_entry = gtk4::SearchEntry::builder().hexpand(true).build();
+    toolbar.append(&search_entry);
 
-    let scrolled = gtk4::ScrolledWindow::builder().vexpand(true).build();
+    let clear_btn = gtk4::Button::builder().label(&tr!("clear-logs-button")).build();
+    toolbar.append(&clear_btn);
 
-    let log_view = gtk4::TextView::builder()
-        .editable(false)
-        .monospace(true)
-        .wrap_mode(gtk4::WrapMode::WordChar)
+    vbox.append(&toolbar);
+
+    let empty_label = gtk4::Label::builder()
+        .label(&tr!("no-logs-yet"))
+        .css_classes(vec!["dim-label"])
+        .margin_top(30)
         .build();
+    vbox.append(&empty_label);
 
-    scrolled.set_child(Some(&log_view));
+    let store = gio::ListStore::new::<glib::BoxedAnyObject>();
+    let filter = gtk4::CustomFilter::new(|_| true);
+    let filter_model = gtk4::FilterListModel::new(Some(store.clone()), Some(filter.clone()));
+    let selection_model = gtk4::NoSelection::new(Some(filter_model));
+
+    let column_view = gtk4::ColumnView::builder()
+        .model(&selection_model)
+        .show_row_separators(true)
+        .build();
+    column_view.append_column(&log_column(
+        &tr!("log-column-time"),
+        false,
+        |entry| {
+            entry
+                .timestamp
+                .map(|t| t.format("%H:%M:%S").to_string())
+                .unwrap_or_else(|| "--:--:--".to_string())
+        },
+        None,
+    ));
+    column_view.append_column(&log_column(
+        &tr!("log-column-level"),
+        false,
+        |entry| entry.level.label().to_string(),
+        Some(|entry| entry.level.css_class()),
+    ));
+    column_view.append_column(&log_column(
+        &tr!("log-column-message"),
+        true,
+        |entry| entry.message.clone(),
+        None,
+    ));
+
+    let scrolled = gtk4::ScrolledWindow::builder()
+        .vexpand(true)
+        .visible(false)
+        .build();
+    scrolled.set_child(Some(&column_view));
     vbox.append(&scrolled);
 
-    (vbox, log_view)
+    store.connect_items_changed(clone!(@strong empty_label, @strong scrolled => move |store, _, _, _| {
+        let empty = store.n_items() == 0;
+        empty_label.set_visible(empty);
+        scrolled.set_visible(!empty);
+    }));
+
+    (
+        vbox,
+        LogViewWidgets {
+            store,
+            filter,
+            search_entry,
+            level_toggles,
+            clear_btn,
+            uptime_label,
+        },
+    )
+}
+
+/// Re-reads the profile-scoped keys (`config::PROFILE_KEYS`) from `cfg` into
+/// the widgets that display them, so switching the active profile (or
+/// creating a new one) doesn't require rebuilding the Settings page.
+fn sync_profile_scoped_fields(cfg: &EnvConfig, widgets: &SettingsWidgets) {
+    widgets.lang_entry.set_text(cfg.get("LANGUAGE").unwrap_or(""));
+    widgets
+        .prompt_view
+        .buffer()
+        .set_text(cfg.get("SYSTEM_PROMPT").unwrap_or(EnvConfig::get_default_system_prompt()));
+
+    let mic_source = cfg.get("MIC_SOURCE").unwrap_or("");
+    if let Some(index) = widgets.mic_devices.iter().position(|(id, _)| id == mic_source) {
+        widgets.mic_combo.set_selected(index as u32);
+    }
 }
 
 fn create_settings_page(config: Rc<RefCell<EnvConfig>>) -> (gtk4::ScrolledWindow, SettingsWidgets) {
@@ -623,25 +1765,108 @@ fn create_settings_page(config: Rc<RefCell<EnvConfig>>) -> (gtk4::ScrolledWindow
 
     // API Key
     let api_group = adw::PreferencesGroup::builder()
-        .title("API Konfiguration")
+        .title(&tr!("api-config-group"))
         .build();
 
-    let api_entry = adw::EntryRow::builder().title("Groq API Key").build();
+    let api_entry = adw::EntryRow::builder().title(&tr!("groq-api-key-title")).build();
     api_entry.set_text(cfg.get("GROQ_API_KEY").unwrap_or(""));
     api_group.add(&api_entry);
     vbox.append(&api_group);
 
+    // Profiles: a named bundle of the profile-scoped keys (SYSTEM_PROMPT,
+    // LANGUAGE, MIC_SOURCE — see `config::PROFILE_KEYS`), so a user can
+    // switch all three at once instead of re-editing them by hand. "Default"
+    // (an empty `ACTIVE_PROFILE`) always sits first.
+    let profiles_group = adw::PreferencesGroup::builder()
+        .title(&tr!("profiles-group-title"))
+        .build();
+
+    let mut profile_names: Vec<String> = cfg.profile_names().iter().map(|s| s.to_string()).collect();
+    profile_names.sort();
+    let active_profile = cfg.get("ACTIVE_PROFILE").unwrap_or("").to_string();
+
+    let mut profile_display_names: Vec<String> = vec![tr!("profile-default-option")];
+    profile_display_names.extend(profile_names.iter().cloned());
+    let profile_display_refs: Vec<&str> = profile_display_names.iter().map(|s| s.as_str()).collect();
+
+    let profile_combo = adw::ComboRow::builder()
+        .title(&tr!("profile-title"))
+        .model(&gtk4::StringList::new(&profile_display_refs))
+        .build();
+    let selected_profile_index = profile_names
+        .iter()
+        .position(|name| *name == active_profile)
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    profile_combo.set_selected(selected_profile_index as u32);
+    profiles_group.add(&profile_combo);
+    let profile_names = Rc::new(RefCell::new(profile_names));
+
+    let new_profile_entry = adw::EntryRow::builder()
+        .title(&tr!("new-profile-title"))
+        .build();
+    profiles_group.add(&new_profile_entry);
+
+    let add_profile_btn = gtk4::Button::builder()
+        .label(&tr!("add-profile-button"))
+        .build();
+    profiles_group.add(&add_profile_btn);
+
+    vbox.append(&profiles_group);
+
     // Recording settings
-    let rec_group = adw::PreferencesGroup::builder().title("Recording").build();
+    let rec_group = adw::PreferencesGroup::builder()
+        .title(&tr!("recording-group-title"))
+        .build();
 
-    let mic_entry = adw::EntryRow::builder()
-        .title("Microphone Source (empty = default)")
+    let mic_devices = list_capture_devices();
+    let mic_device_names: Vec<&str> = mic_devices.iter().map(|(_, name)| name.as_str()).collect();
+    let mic_combo = adw::ComboRow::builder()
+        .title(&tr!("microphone-title"))
+        .model(&gtk4::StringList::new(&mic_device_names))
+        .build();
+    let current_mic = cfg.get("MIC_SOURCE").unwrap_or("");
+    let selected_index = mic_devices
+        .iter()
+        .position(|(id, _)| id == current_mic)
+        .unwrap_or(0);
+    mic_combo.set_selected(selected_index as u32);
+    rec_group.add(&mic_combo);
+
+    let mic_level_bar = gtk4::LevelBar::builder()
+        .min_value(0.0)
+        .max_value(1.0)
+        .value(0.0)
+        .width_request(160)
+        .valign(gtk4::Align::Center)
+        .build();
+    let mic_level_row = adw::ActionRow::builder().title(&tr!("input-level-title")).build();
+    mic_level_row.add_suffix(&mic_level_bar);
+    rec_group.add(&mic_level_row);
+
+    // Scrolling waveform + RMS/peak readout, so users can confirm the mic is
+    // live and catch clipping before Whisper ever sees the audio. Driven by
+    // `start_mic_waveform_tick` in `build_ui`, which shows genuine per-buffer
+    // PCM amplitude while `app.toggle-recording` has a capture pipeline
+    // running, falling back to the same capture-level watcher as
+    // `mic_level_bar` above the rest of the time.
+    let mic_waveform_area = gtk4::DrawingArea::builder()
+        .width_request(160)
+        .height_request(40)
+        .build();
+    let mic_waveform_readout = gtk4::Label::builder()
+        .label(&tr!("rms-peak-readout", rms = "0", peak = "0"))
+        .css_classes(vec!["dim-label", "caption"])
         .build();
-    mic_entry.set_text(cfg.get("MIC_SOURCE").unwrap_or(""));
-    rec_group.add(&mic_entry);
+    let mic_waveform_box = gtk4::Box::new(gtk4::Orientation::Vertical, 2);
+    mic_waveform_box.append(&mic_waveform_area);
+    mic_waveform_box.append(&mic_waveform_readout);
+    let mic_waveform_row = adw::ActionRow::builder().title(&tr!("live-waveform-title")).build();
+    mic_waveform_row.add_suffix(&mic_waveform_box);
+    rec_group.add(&mic_waveform_row);
 
     let lang_entry = adw::EntryRow::builder()
-        .title("Language (e.g. 'de', 'en', empty = auto)")
+        .title(&tr!("language-entry-title"))
         .build();
     lang_entry.set_text(cfg.get("LANGUAGE").unwrap_or(""));
     rec_group.add(&lang_entry);
@@ -649,26 +1874,75 @@ fn create_settings_page(config: Rc<RefCell<EnvConfig>>) -> (gtk4::ScrolledWindow
     vbox.append(&rec_group);
 
     // UI settings
-    let ui_group = adw::PreferencesGroup::builder().title("Interface").build();
+    let ui_group = adw::PreferencesGroup::builder()
+        .title(&tr!("interface-group-title"))
+        .build();
 
-    let notif_switch = adw::SwitchRow::builder().title("Notifications").build();
+    let notif_switch = adw::SwitchRow::builder().title(&tr!("notifications-title")).build();
     notif_switch.set_active(cfg.get("NOTIFICATIONS").unwrap_or("true") == "true");
     ui_group.add(&notif_switch);
 
-    let tray_switch = adw::SwitchRow::builder().title("Tray Icon").build();
+    let tray_switch = adw::SwitchRow::builder().title(&tr!("tray-icon-title")).build();
     tray_switch.set_active(cfg.get("TRAY_ICON").unwrap_or("true") == "true");
     ui_group.add(&tray_switch);
 
     vbox.append(&ui_group);
 
+    // Translation mode: TRANSLATE_TO enables it, TRANSLATE_COMMAND is the
+    // locally-spawned process `translate::Translator` round-trips marked-up
+    // text through (see `translate.rs`). Leaving either empty makes the
+    // "Translate" button on recording rows do nothing when clicked.
+    let translate_group = adw::PreferencesGroup::builder()
+        .title(&tr!("translation-group-title"))
+        .build();
+
+    let translate_to_entry = adw::EntryRow::builder()
+        .title(&tr!("translate-to-title"))
+        .build();
+    translate_to_entry.set_text(cfg.get("TRANSLATE_TO").unwrap_or(""));
+    translate_group.add(&translate_to_entry);
+
+    let translate_command_entry = adw::EntryRow::builder()
+        .title(&tr!("translate-command-title"))
+        .build();
+    translate_command_entry.set_text(cfg.get("TRANSLATE_COMMAND").unwrap_or(""));
+    translate_group.add(&translate_command_entry);
+
+    vbox.append(&translate_group);
+
+    // Grammar check: GRAMMAR_CHECK enables it, and grammar::GrammarChecker
+    // prefers GRAMMAR_CHECK_ENDPOINT (an HTTP checker) over
+    // GRAMMAR_CHECK_COMMAND (a locally-spawned one) when both are set.
+    let grammar_group = adw::PreferencesGroup::builder()
+        .title(&tr!("grammar-check-group-title"))
+        .build();
+
+    let grammar_check_switch = adw::SwitchRow::builder().title(&tr!("grammar-check-title")).build();
+    grammar_check_switch.set_active(cfg.get("GRAMMAR_CHECK").unwrap_or("false") == "true");
+    grammar_group.add(&grammar_check_switch);
+
+    let grammar_endpoint_entry = adw::EntryRow::builder()
+        .title(&tr!("grammar-check-endpoint-title"))
+        .build();
+    grammar_endpoint_entry.set_text(cfg.get("GRAMMAR_CHECK_ENDPOINT").unwrap_or(""));
+    grammar_group.add(&grammar_endpoint_entry);
+
+    let grammar_command_entry = adw::EntryRow::builder()
+        .title(&tr!("grammar-check-command-title"))
+        .build();
+    grammar_command_entry.set_text(cfg.get("GRAMMAR_CHECK_COMMAND").unwrap_or(""));
+    grammar_group.add(&grammar_command_entry);
+
+    vbox.append(&grammar_group);
+
     // System prompt
     let prompt_group = adw::PreferencesGroup::builder()
-        .title("System Prompt")
+        .title(&tr!("system-prompt-group-title"))
         .build();
     let prompt_box = gtk4::Box::new(gtk4::Orientation::Vertical, 8);
 
     let prompt_info = gtk4::Label::builder()
-        .label("Der System Prompt wird dem LLM gegeben, um die Formatierung zu steuern:")
+        .label(&tr!("system-prompt-info"))
         .css_classes(vec!["dim-label"])
         .xalign(0.0)
         .wrap(true)
@@ -690,42 +1964,121 @@ fn create_settings_page(config: Rc<RefCell<EnvConfig>>) -> (gtk4::ScrolledWindow
     prompt_box.append(&prompt_scroll);
 
     let reset_prompt_btn = gtk4::Button::builder()
-        .label("Reset to Default")
+        .label(&tr!("reset-prompt-button"))
         .build();
     prompt_box.append(&reset_prompt_btn);
 
     prompt_group.add(&prompt_box);
     vbox.append(&prompt_group);
 
+    // Keyboard shortcuts
+    let shortcuts_group = adw::PreferencesGroup::builder()
+        .title(&tr!("keyboard-shortcuts-group-title"))
+        .build();
+
+    let capturing_shortcut: Rc<RefCell<Option<usize>>> = Rc::new(RefCell::new(None));
+    let mut shortcut_rows = Vec::new();
+
+    for (index, (action, label, _default_accel)) in config::ACCEL_ACTIONS.iter().enumerate() {
+        let current = cfg.accel_for(action).to_string();
+
+        let row = adw::ActionRow::builder().title(*label).build();
+        let capture_btn = gtk4::Button::builder()
+            .label(&current)
+            .valign(gtk4::Align::Center)
+            .build();
+        row.add_suffix(&capture_btn);
+        shortcuts_group.add(&row);
+
+        let current_accel = Rc::new(RefCell::new(current));
+
+        capture_btn.connect_clicked(clone!(@strong capturing_shortcut, @strong capture_btn => move |_| {
+            *capturing_shortcut.borrow_mut() = Some(index);
+            capture_btn.set_label(&tr!("press-key-button"));
+        }));
+
+        shortcut_rows.push(ShortcutRow {
+            action: action.to_string(),
+            row,
+            capture_btn,
+            current_accel,
+        });
+    }
+
+    vbox.append(&shortcuts_group);
+
+    let reset_shortcuts_btn = gtk4::Button::builder()
+        .label(&tr!("reset-shortcuts-button"))
+        .build();
+    vbox.append(&reset_shortcuts_btn);
+
+    reset_shortcuts_btn.connect_clicked(clone!(@strong shortcut_rows => move |_| {
+        for (row, (_, _, default_accel)) in shortcut_rows.iter().zip(config::ACCEL_ACTIONS.iter()) {
+            *row.current_accel.borrow_mut() = default_accel.to_string();
+            row.capture_btn.set_label(default_accel);
+        }
+    }));
+
     // Save button
     let save_btn = gtk4::Button::builder()
-        .label("Save Settings")
+        .label(&tr!("save-settings-button"))
         .css_classes(vec!["suggested-action"])
         .build();
     vbox.append(&save_btn);
 
-    // Clear logs button (store for later use)
-    let clear_logs_btn = gtk4::Button::new();
-
     scrolled.set_child(Some(&vbox));
 
     (
         scrolled,
         SettingsWidgets {
             api_entry,
-            mic_entry,
+            profile_combo,
+            profile_names,
+            new_profile_entry,
+            add_profile_btn,
+            mic_combo,
+            mic_devices,
+            mic_level_bar,
+            mic_waveform_area,
+            mic_waveform_readout,
             lang_entry,
             notif_switch,
             tray_switch,
+            translate_to_entry,
+            translate_command_entry,
+            grammar_check_switch,
+            grammar_endpoint_entry,
+            grammar_command_entry,
             prompt_view,
             reset_prompt_btn,
+            shortcut_rows,
+            reset_shortcuts_btn,
+            capturing_shortcut,
             save_btn,
-            clear_logs_btn,
         },
     )
 }
 
-fn create_corrections_page(corrections_box: gtk4::Box) -> gtk4::Box {
+/// Order backing the corrections page's per-row mode dropdown.
+const MATCH_MODE_ORDER: [MatchMode; 4] = [
+    MatchMode::Exact,
+    MatchMode::CaseInsensitive,
+    MatchMode::Regex,
+    MatchMode::Phonetic,
+];
+
+fn match_mode_index(mode: MatchMode) -> u32 {
+    MATCH_MODE_ORDER.iter().position(|m| *m == mode).unwrap_or(0) as u32
+}
+
+fn match_mode_from_index(index: u32) -> MatchMode {
+    MATCH_MODE_ORDER
+        .get(index as usize)
+        .copied()
+        .unwrap_or(MatchMode::Exact)
+}
+
+fn create_corrections_page(corrections_box: gtk4::Box) -> (gtk4::Box, gtk4::Button) {
     let vbox = gtk4::Box::new(gtk4::Orientation::Vertical, 12);
     vbox.set_margin_top(12);
     vbox.set_margin_bottom(12);
@@ -733,7 +2086,11 @@ fn create_corrections_page(corrections_box: gtk4::Box) -> gtk4::Box {
     vbox.set_margin_end(12);
 
     let info = gtk4::Label::builder()
-        .label("<b>Saved Corrections</b>\n\nThese corrections are provided to the LLM as context\nto better understand your speech patterns.")
+        .label(&format!(
+            "<b>{}</b>\n\n{}",
+            tr!("corrections-heading"),
+            tr!("corrections-description")
+        ))
         .use_markup(true)
         .xalign(0.0)
         .wrap(true)
@@ -741,7 +2098,7 @@ fn create_corrections_page(corrections_box: gtk4::Box) -> gtk4::Box {
     vbox.append(&info);
 
     let export_btn = gtk4::Button::builder()
-        .label("Export Corrections as Prompt Context")
+        .label(&tr!("export-corrections-button"))
         .build();
     vbox.append(&export_btn);
 
@@ -749,7 +2106,7 @@ fn create_corrections_page(corrections_box: gtk4::Box) -> gtk4::Box {
     scrolled.set_child(Some(&corrections_box));
     vbox.append(&scrolled);
 
-    vbox
+    (vbox, export_btn)
 }
 
 fn refresh_corrections(state: &Rc<AppState>) {
@@ -762,12 +2119,19 @@ fn refresh_corrections(state: &Rc<AppState>) {
 
     if corrections.is_empty() {
         let empty = gtk4::Label::builder()
-            .label("No corrections yet.\n\nClick 'Save Correction' on a recording to add training data.")
+            .label(&tr!("no-corrections-yet"))
             .css_classes(vec!["dim-label"])
             .margin_top(30)
             .build();
         state.corrections_box.append(&empty);
     } else {
+        let count = gtk4::Label::builder()
+            .label(&tr!("correction-count", count = corrections.len()))
+            .css_classes(vec!["dim-label"])
+            .xalign(0.0)
+            .build();
+        state.corrections_box.append(&count);
+
         for c in corrections {
             let row = gtk4::Box::new(gtk4::Orientation::Horizontal, 8);
             row.set_margin_start(8);
@@ -775,6 +2139,20 @@ fn refresh_corrections(state: &Rc<AppState>) {
             row.set_margin_top(4);
             row.set_margin_bottom(4);
 
+            // Doubles as the mode badge and the control for changing it.
+            let mode_labels: Vec<&str> = MATCH_MODE_ORDER.iter().map(|m| m.label()).collect();
+            let mode_dropdown = gtk4::DropDown::from_strings(&mode_labels);
+            mode_dropdown.set_selected(match_mode_index(c.match_mode));
+            mode_dropdown.add_css_class("caption");
+            let correction_id = c.id;
+            let fuzzy_threshold = c.fuzzy_threshold;
+            mode_dropdown.connect_selected_notify(clone!(@strong state => move |dropdown| {
+                let mode = match_mode_from_index(dropdown.selected());
+                let _ = state.db.set_correction_match_mode(correction_id, mode, fuzzy_threshold);
+                refresh_corrections(&state);
+            }));
+            row.append(&mode_dropdown);
+
             let pattern = gtk4::Label::builder()
                 .label(&format!("\"{}\"", c.whisper_pattern))
                 .css_classes(vec!["dim-label"])
@@ -797,17 +2175,43 @@ fn refresh_corrections(state: &Rc<AppState>) {
     }
 }
 
+/// Reads whatever has been appended to the log file since
+/// `state.log_read_offset` and parses each new line into the log store, so
+/// re-reading never costs more than the bytes actually added since the
+/// last tick. Resets to a full read if the file shrank (rotated or
+/// cleared out from under us).
 fn refresh_logs(state: &Rc<AppState>) {
     let log_file = get_log_file();
-    if log_file.exists() {
-        if let Ok(content) = std::fs::read_to_string(&log_file) {
-            state.log_view.buffer().set_text(&content);
+    let Ok(metadata) = std::fs::metadata(&log_file) else {
+        return;
+    };
+
+    let mut offset = *state.log_read_offset.borrow();
+    if metadata.len() < offset {
+        offset = 0;
+        state.log_store.remove_all();
+    }
+
+    let Ok(mut file) = std::fs::File::open(&log_file) else {
+        return;
+    };
+    if file.seek(SeekFrom::Start(offset)).is_err() {
+        return;
+    }
+
+    let mut new_bytes = Vec::new();
+    if file.read_to_end(&mut new_bytes).is_err() {
+        return;
+    }
+    *state.log_read_offset.borrow_mut() = offset + new_bytes.len() as u64;
+
+    for line in String::from_utf8_lossy(&new_bytes).lines() {
+        if line.trim().is_empty() {
+            continue;
         }
-    } else {
         state
-            .log_view
-            .buffer()
-            .set_text("No logs yet.\n\nLogs will be created on next voice input.");
+            .log_store
+            .append(&glib::BoxedAnyObject::new(log_view::parse_line(line)));
     }
 }
 
@@ -815,6 +2219,8 @@ fn start_log_watcher(state: Rc<AppState>) {
     refresh_logs(&state);
 
     let id = glib::timeout_add_seconds_local(2, clone!(@strong state => move || {
+        state.log_uptime_label.set_text(&tr!("uptime-label", value = log_view::format_uptime(state.app_start)));
+
         let log_file = get_log_file();
         if log_file.exists() {
             if let Ok(metadata) = std::fs::metadata(&log_file) {
@@ -0,0 +1,187 @@
+//! Sentence-aligned translation mode. To keep the translated output aligned
+//! with the original's sentence structure, each source sentence is wrapped
+//! in a lightweight numbered marker (`[[1]] ... [[2]] ...`) before being
+//! sent to the translator, and the matching markers are parsed back out of
+//! the reply. If markers come back missing, merged, or extra, we fall back
+//! to splitting the translated text proportionally so no text is dropped.
+//!
+//! Configured via `TRANSLATE_TO`/`TRANSLATE_COMMAND` in `EnvConfig`, the
+//! same shape as `grammar::EmbeddedChecker`: a locally-spawned process reads
+//! one line of marker-wrapped text on stdin and writes one line of
+//! translated, marker-aligned text back on stdout. See `create_recording_row`'s
+//! "Translate" button in `main.rs` for the one place this currently runs.
+
+use crate::config::EnvConfig;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, Command, Stdio};
+
+/// A locally-spawned translation process, given the target language via the
+/// `TRANSLATE_TO` environment variable so `TRANSLATE_COMMAND` itself doesn't
+/// need to embed it.
+pub struct Translator {
+    child: Child,
+}
+
+impl Translator {
+    /// Spawns `command` through the shell with `TRANSLATE_TO=target` set.
+    pub fn spawn(command: &str, target: &str) -> io::Result<Self> {
+        let child = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .env("TRANSLATE_TO", target)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        Ok(Translator { child })
+    }
+
+    /// Wraps `text` with alignment markers, round-trips it through the
+    /// spawned process, and parses the markers back out of the reply.
+    pub fn translate(&mut self, text: &str) -> io::Result<String> {
+        let (sentences, marked) = wrap_sentences_with_markers(text);
+
+        let stdin = self
+            .child
+            .stdin
+            .as_mut()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::BrokenPipe, "translator stdin closed"))?;
+        writeln!(stdin, "{}", marked.replace('\n', " "))?;
+        stdin.flush()?;
+
+        let stdout = self
+            .child
+            .stdout
+            .as_mut()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::BrokenPipe, "translator stdout closed"))?;
+        let mut reply = String::new();
+        BufReader::new(stdout).read_line(&mut reply)?;
+
+        Ok(parse_marked_translation(reply.trim(), sentences.len()).join(" "))
+    }
+}
+
+impl Drop for Translator {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+    }
+}
+
+/// Builds a [`Translator`] from `cfg`, or `None` if translation isn't
+/// configured (`TRANSLATE_TO` or `TRANSLATE_COMMAND` is empty).
+pub fn from_config(cfg: &EnvConfig) -> Option<io::Result<Translator>> {
+    let target = cfg.get("TRANSLATE_TO").unwrap_or("");
+    if target.is_empty() {
+        return None;
+    }
+
+    let command = cfg.get("TRANSLATE_COMMAND").unwrap_or("");
+    if command.is_empty() {
+        return None;
+    }
+
+    Some(Translator::spawn(command, target))
+}
+
+/// Splits `text` into sentences and wraps each one with a `[[n]]` marker,
+/// returning both the original sentences (for alignment bookkeeping) and
+/// the marked-up string to send to the translator.
+pub fn wrap_sentences_with_markers(text: &str) -> (Vec<String>, String) {
+    let sentences = split_into_sentences(text);
+    let marked = sentences
+        .iter()
+        .enumerate()
+        .map(|(i, sentence)| format!("[[{}]] {}", i + 1, sentence))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (sentences, marked)
+}
+
+/// Parses a translated reply back into sentence-aligned segments. Prefers
+/// the `[[n]]` markers if they round-tripped cleanly; otherwise falls back
+/// to [`proportional_split`] over at most `original_sentence_count`
+/// segments.
+pub fn parse_marked_translation(translated: &str, original_sentence_count: usize) -> Vec<String> {
+    let marked_segments = split_on_markers(translated);
+
+    if marked_segments.len() == original_sentence_count && original_sentence_count > 0 {
+        return marked_segments;
+    }
+
+    proportional_split(translated, original_sentence_count.max(1))
+}
+
+fn split_into_sentences(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        current.push(c);
+        if matches!(c, '.' | '!' | '?') {
+            let at_boundary = chars.get(i + 1).map(|next| next.is_whitespace()).unwrap_or(true);
+            if at_boundary {
+                sentences.push(current.trim().to_string());
+                current.clear();
+            }
+        }
+    }
+
+    if !current.trim().is_empty() {
+        sentences.push(current.trim().to_string());
+    }
+
+    sentences
+}
+
+/// Finds every `[[n]]` marker in `text` and returns the content between
+/// consecutive markers, ordered by marker number (translators sometimes
+/// reorder sentences across languages).
+fn split_on_markers(text: &str) -> Vec<String> {
+    let mut markers: Vec<(usize, usize, usize)> = Vec::new(); // (marker_start, content_start, number)
+    let mut search_from = 0;
+
+    while let Some(rel_start) = text[search_from..].find("[[") {
+        let marker_start = search_from + rel_start;
+        if let Some(rel_end) = text[marker_start..].find("]]") {
+            let marker_end = marker_start + rel_end;
+            let number_str = &text[marker_start + 2..marker_end];
+            if let Ok(number) = number_str.trim().parse::<usize>() {
+                markers.push((marker_start, marker_end + 2, number));
+            }
+            search_from = marker_end + 2;
+        } else {
+            break;
+        }
+    }
+
+    let mut segments: Vec<(usize, String)> = markers
+        .iter()
+        .enumerate()
+        .map(|(i, &(_, content_start, number))| {
+            let content_end = markers.get(i + 1).map(|m| m.0).unwrap_or(text.len());
+            (number, text[content_start..content_end].trim().to_string())
+        })
+        .collect();
+
+    segments.sort_by_key(|(number, _)| *number);
+    segments.into_iter().map(|(_, content)| content).collect()
+}
+
+/// Splits `text` into chunks of `ceil(words / parts)` words each, used when
+/// the translator didn't preserve the sentence markers. This can give fewer
+/// than `parts` chunks (5 words over 4 parts gives 2 + 2 + 1); empty `text`
+/// gives `parts` empty strings.
+fn proportional_split(text: &str, parts: usize) -> Vec<String> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    if words.is_empty() {
+        return vec![String::new(); parts];
+    }
+
+    let chunk_size = (words.len() as f64 / parts as f64).ceil().max(1.0) as usize;
+    words
+        .chunks(chunk_size)
+        .map(|chunk| chunk.join(" "))
+        .collect()
+}
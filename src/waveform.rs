@@ -0,0 +1,135 @@
+//! Playback and waveform-peak decoding for the History tab's inline audio
+//! review (see `create_recording_row` in `main.rs`). Peaks are decoded once
+//! per row via a throwaway offline pipeline; playback uses a `playbin`
+//! driven by whatever glib main loop the caller is already running.
+
+use gstreamer as gst;
+use gstreamer::prelude::*;
+use gstreamer_app as gst_app;
+use std::path::Path;
+
+/// Mirrors the subset of `gst::State` the UI cares about, so `main.rs`
+/// doesn't need to depend on the full GStreamer state machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaybackState {
+    Stopped,
+    Playing,
+    Paused,
+}
+
+/// A `playbin` wrapped for single-file playback of one recording's audio.
+pub struct Player {
+    playbin: gst::Element,
+}
+
+impl Player {
+    pub fn new(path: &Path) -> Option<Self> {
+        gst::init().ok()?;
+        let playbin = gst::ElementFactory::make("playbin").build().ok()?;
+        playbin.set_property("uri", format!("file://{}", path.to_string_lossy()));
+        Some(Player { playbin })
+    }
+
+    pub fn play(&self) {
+        let _ = self.playbin.set_state(gst::State::Playing);
+    }
+
+    pub fn pause(&self) {
+        let _ = self.playbin.set_state(gst::State::Paused);
+    }
+
+    pub fn state(&self) -> PlaybackState {
+        match self.playbin.current_state() {
+            gst::State::Playing => PlaybackState::Playing,
+            gst::State::Paused => PlaybackState::Paused,
+            _ => PlaybackState::Stopped,
+        }
+    }
+
+    /// Current playback position in milliseconds, if the pipeline knows it.
+    pub fn position_ms(&self) -> Option<i64> {
+        self.playbin
+            .query_position::<gst::ClockTime>()
+            .map(|pos| pos.mseconds() as i64)
+    }
+
+    /// Total duration in milliseconds, if the pipeline has pre-rolled enough
+    /// to report it.
+    pub fn duration_ms(&self) -> Option<i64> {
+        self.playbin
+            .query_duration::<gst::ClockTime>()
+            .map(|dur| dur.mseconds() as i64)
+    }
+
+    /// Whether an end-of-stream message is waiting on the pipeline's bus.
+    /// Pops that message, so only the first call after end-of-stream
+    /// returns true.
+    pub fn is_eos(&self) -> bool {
+        self.playbin
+            .bus()
+            .map(|bus| bus.pop_filtered(&[gst::MessageType::Eos]).is_some())
+            .unwrap_or(false)
+    }
+}
+
+impl Drop for Player {
+    fn drop(&mut self) {
+        let _ = self.playbin.set_state(gst::State::Null);
+    }
+}
+
+/// Decodes `path` into `buckets` peak amplitudes (0.0-1.0), one per waveform
+/// bar, by running the file through a throwaway decode pipeline
+/// synchronously. Meant to be called once per row and cached by the caller.
+pub fn decode_peaks(path: &Path, buckets: usize) -> Option<Vec<f32>> {
+    gst::init().ok()?;
+
+    let pipeline_desc = format!(
+        "uridecodebin uri=file://{} ! audioconvert ! audio/x-raw,channels=1,format=S16LE ! appsink name=sink sync=false",
+        path.to_string_lossy()
+    );
+    let pipeline = gst::parse::launch(&pipeline_desc)
+        .ok()?
+        .downcast::<gst::Pipeline>()
+        .ok()?;
+    let appsink = pipeline
+        .by_name("sink")?
+        .downcast::<gst_app::AppSink>()
+        .ok()?;
+
+    pipeline.set_state(gst::State::Playing).ok()?;
+
+    let mut samples: Vec<f32> = Vec::new();
+    while let Ok(sample) = appsink.pull_sample() {
+        let Some(buffer) = sample.buffer() else {
+            continue;
+        };
+        let Ok(map) = buffer.map_readable() else {
+            continue;
+        };
+        for chunk in map.as_slice().chunks_exact(2) {
+            let raw = i16::from_le_bytes([chunk[0], chunk[1]]);
+            samples.push(raw as f32 / i16::MAX as f32);
+        }
+    }
+
+    let _ = pipeline.set_state(gst::State::Null);
+
+    if samples.is_empty() {
+        None
+    } else {
+        Some(bucket_peaks(&samples, buckets))
+    }
+}
+
+/// Reduces `samples` to `buckets` peaks by taking the max absolute value
+/// within each bucket's slice of source samples.
+fn bucket_peaks(samples: &[f32], buckets: usize) -> Vec<f32> {
+    let buckets = buckets.max(1);
+    let chunk_size = (samples.len() / buckets).max(1);
+    samples
+        .chunks(chunk_size)
+        .take(buckets)
+        .map(|chunk| chunk.iter().fold(0.0f32, |max, &s| max.max(s.abs())))
+        .collect()
+}